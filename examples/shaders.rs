@@ -0,0 +1,11 @@
+//! Prints the generated shader source: `cargo run --example shaders -- [wgsl|glsl]`.
+
+use cga::shader::{generate, Language};
+
+fn main() {
+    let language = match std::env::args().nth(1).as_deref() {
+        Some("glsl") => Language::Glsl,
+        _ => Language::Wgsl,
+    };
+    print!("{}", generate(language));
+}
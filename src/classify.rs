@@ -0,0 +1,293 @@
+//! Recognizing the object held by a general multivector, for debugging long computations and
+//! validating coefficients that come from other tools.
+
+use num_traits::zero;
+use simba::scalar::RealField;
+
+use crate::d3::direction::DVector;
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::round::{Circle, Pair, Point, Sphere};
+use crate::d3::transform::{Dilator, Motor, Rotor, Translator};
+use crate::params::round_size;
+use crate::r410::{blade_grade, blade_index, BLADE_MASKS};
+use crate::{Euclidean, Multivec, Space, R410};
+
+/// Whether a round has a positive, negative or zero squared radius.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Reality {
+    Real,
+    Imaginary,
+    /// A round of zero size, which is tangent to its carrier at its center.
+    Tangent,
+}
+
+/// The kind of object found by [`classify`], holding the object itself when it has a type of its
+/// own in Euclidean space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ObjectKind<T> {
+    /// Every coefficient is zero.
+    Zero,
+    Scalar(T),
+    /// A null vector.
+    Point(Point<T>),
+    DSphere(DSphere<T>, Reality),
+    Pair(Pair<T>, Reality),
+    Circle(Circle<T>, Reality),
+    Sphere(Sphere<T>, Reality),
+    FPoint(FPoint<T>),
+    Line(Line<T>),
+    Plane(Plane<T>),
+    DPlane(DPlane<T>),
+    DLine(DLine<T>),
+    /// A free vector `v∧ni`.
+    Direction(DVector<T>),
+    /// Any other blade, such as free blades of other grades, dual flat points and pseudoscalars.
+    Blade { grade: u32, null: bool },
+    Rotor(Rotor<T>),
+    Translator(Translator<T>),
+    Motor(Motor<T>),
+    Dilator(Dilator<T>),
+    /// A versor without a type of its own, made up of only even or only odd grades.
+    Versor { odd: bool },
+    /// Anything else, such as the sum of objects of different grades.
+    Mixed,
+}
+
+/// Classifies a general multivector given by its coefficients in the order of
+/// [`ganja::BASIS`](crate::ganja::BASIS), as a Euclidean object.
+///
+/// The coefficients are first scaled to unit length, and `tol` is the absolute tolerance on
+/// every quantity that must vanish, such as the components outside of an object's grade.
+pub fn classify<T: RealField + Copy>(coefficients: [T; 32], tol: T) -> ObjectKind<T> {
+    let v = R410::from_array(coefficients);
+    let norm = coefficients.iter().fold(T::zero(), |acc, &c| acc + c * c).sqrt();
+    if norm <= tol {
+        return ObjectKind::Zero;
+    }
+    let u = v / norm;
+    let small = |x: R410<T>| x.into_array().iter().all(|c| c.abs() <= tol);
+
+    let mut grades = [false; 6];
+    for (i, c) in u.into_array().iter().enumerate() {
+        if c.abs() > tol {
+            grades[blade_grade(i) as usize] = true;
+        }
+    }
+    let present: Vec<u32> = (0..6).filter(|&k| grades[k as usize]).collect();
+
+    if let [grade] = present[..] {
+        // A bivector B is a blade when B∧B, the only part of B² besides the scalar, vanishes.
+        // Trivectors are dual to bivectors.
+        let square = |b: R410<T>| R410 { s: zero(), ..b * b };
+        let blade = match grade {
+            2 => small(square(u)),
+            3 => small(square(u.dual())),
+            _ => true,
+        };
+        if blade {
+            return classify_blade(v, u, grade, tol, norm * tol);
+        }
+    }
+
+    let odd = present.iter().all(|k| k % 2 == 1);
+    if (odd || present.iter().all(|k| k % 2 == 0)) && is_versor(u, tol) {
+        let tol = norm * tol;
+        return fit(v, tol)
+            .map(ObjectKind::Rotor)
+            .or_else(|| fit(v, tol).map(ObjectKind::Dilator))
+            .or_else(|| fit(v, tol).map(ObjectKind::Translator))
+            .or_else(|| fit(v, tol).map(ObjectKind::Motor))
+            .unwrap_or(ObjectKind::Versor { odd });
+    }
+    ObjectKind::Mixed
+}
+
+/// Whether `u` times its reverse is a nonzero scalar, and its sandwich maps vectors to vectors.
+fn is_versor<T: RealField + Copy>(u: R410<T>, tol: T) -> bool {
+    let square = u * u.reverse();
+    let scalar = square.s;
+    let rest = R410 { s: zero(), ..square };
+    if scalar.abs() <= tol || rest.into_array().iter().any(|c| c.abs() > tol) {
+        return false;
+    }
+    (1..6).all(|i| {
+        let mut e = [zero(); 32];
+        e[i] = T::one();
+        let image = u.involute() * R410::from_array(e) * u.reverse();
+        image
+            .into_array()
+            .iter()
+            .enumerate()
+            .all(|(j, c)| blade_grade(j) == 1 || c.abs() <= tol * scalar.abs())
+    })
+}
+
+/// Classifies the blade `v` of the given grade, where `u` is `v` scaled to unit length. The
+/// tolerance `tol` applies to `u` and `fit_tol` to `v`.
+fn classify_blade<T: RealField + Copy>(
+    v: R410<T>,
+    u: R410<T>,
+    grade: u32,
+    tol: T,
+    fit_tol: T,
+) -> ObjectKind<T> {
+    let small = |x: R410<T>| x.into_array().iter().all(|c| c.abs() <= tol);
+    let null = (u * u.reverse()).s.abs() <= tol;
+
+    let ni = Euclidean::infinity();
+    let half = T::from_subset(&0.5);
+    let contraction = (ni * u - u.involute() * ni) * half;
+    let outer = (ni * u + u.involute() * ni) * half;
+
+    let kind = match (grade, small(contraction), small(outer)) {
+        (0, _, _) => Some(ObjectKind::Scalar(v.s)),
+        (1, _, _) if null && !small(contraction) => fit(v, fit_tol).map(ObjectKind::Point),
+        (2, true, true) => fit(v, fit_tol).map(ObjectKind::Direction),
+        (2, false, true) => fit(v, fit_tol).map(ObjectKind::FPoint),
+        (3, false, true) => fit(v, fit_tol).map(ObjectKind::Line),
+        (4, false, true) => fit(v, fit_tol).map(ObjectKind::Plane),
+        (1, true, false) => fit(v, fit_tol).map(ObjectKind::DPlane),
+        (2, true, false) => fit(v, fit_tol).map(ObjectKind::DLine),
+        (1..=4, false, false) => {
+            // Dual spheres have the opposite sign, see `ObjectParams::size`.
+            let size = if grade == 1 { -round_size(u) } else { round_size(u) };
+            let reality = if size.abs() <= tol {
+                Reality::Tangent
+            } else if size > zero() {
+                Reality::Real
+            } else {
+                Reality::Imaginary
+            };
+            match grade {
+                1 => fit(v, fit_tol).map(|x| ObjectKind::DSphere(x, reality)),
+                2 => fit(v, fit_tol).map(|x| ObjectKind::Pair(x, reality)),
+                3 => fit(v, fit_tol).map(|x| ObjectKind::Circle(x, reality)),
+                _ => fit(v, fit_tol).map(|x| ObjectKind::Sphere(x, reality)),
+            }
+        }
+        _ => None,
+    };
+    kind.unwrap_or(ObjectKind::Blade { grade, null })
+}
+
+/// Converts `v` into `X` if doing so changes no component by more than `tol`, averaging the
+/// halves of components that `X` joins into multiples of infinity.
+fn fit<T: RealField + Copy, X: Multivec<Element = T> + Copy>(v: R410<T>, tol: T) -> Option<X> {
+    let joined = if X::joins(&v) { v } else { average_halves(v) };
+    if !X::joins(&joined) {
+        return None;
+    }
+    let x = X::from_mv(joined);
+    let error = x.into_mv() - v;
+    if error.into_array().iter().all(|c| c.abs() <= tol) {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Replaces the `E∧ep` and `E∧en` components of every Euclidean blade `E` by their average.
+fn average_halves<T: RealField + Copy>(v: R410<T>) -> R410<T> {
+    const P: u8 = 0b01000;
+    const N: u8 = 0b10000;
+    let mut a = v.into_array();
+    for &mask in BLADE_MASKS.iter().filter(|&&m| m & (P | N) == 0) {
+        let (p, n) = (blade_index(mask | P), blade_index(mask | N));
+        let average = (a[p] + a[n]) * T::from_subset(&0.5);
+        a[p] = average;
+        a[n] = average;
+    }
+    R410::from_array(a)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::free::Vector;
+    use crate::ganja::Ganja;
+    use crate::Outer;
+
+    const TOL: f64 = 1e-9;
+
+    fn point(x: f64, y: f64, z: f64) -> Point<f64> {
+        Point::new([x, y, z])
+    }
+
+    #[test]
+    fn test_blades() {
+        let (p, q, r, s) = (
+            point(1.0, 2.0, 0.0),
+            point(-1.0, 0.0, 3.0),
+            point(0.0, 1.0, 1.0),
+            point(2.0, 2.0, 2.0),
+        );
+        let pair = p.outer(q);
+        let circle = pair.outer(r);
+        let sphere = circle.outer(s);
+
+        assert_eq!(classify([0.0; 32], TOL), ObjectKind::Zero);
+        assert_eq!(classify(Rotor::identity().to_ganja(), TOL), ObjectKind::Scalar(1.0));
+        assert_eq!(classify(p.to_ganja(), TOL), ObjectKind::Point(p));
+        assert_eq!(classify(pair.to_ganja(), TOL), ObjectKind::Pair(pair, Reality::Real));
+        assert_eq!(classify(circle.to_ganja(), TOL), ObjectKind::Circle(circle, Reality::Real));
+        assert_eq!(classify(sphere.to_ganja(), TOL), ObjectKind::Sphere(sphere, Reality::Real));
+        let dual = p.into_sphere(2.0);
+        assert_eq!(classify(dual.to_ganja(), TOL), ObjectKind::DSphere(dual, Reality::Real));
+
+        assert_eq!(classify(p.into_flat().to_ganja(), TOL), ObjectKind::FPoint(p.into_flat()));
+        assert_eq!(classify(pair.extend().to_ganja(), TOL), ObjectKind::Line(pair.extend()));
+        assert_eq!(classify(circle.extend().to_ganja(), TOL), ObjectKind::Plane(circle.extend()));
+        let d1: DPlane<f64> = Vector::new(1.0, 2.0, 3.0).normal(1.5);
+        let d2: DPlane<f64> = Vector::new(0.0, -1.0, 1.0).normal(-0.5);
+        let dual = DLine::from_mv(d1.into_mv() ^ d2.into_mv());
+        assert_eq!(classify(d1.to_ganja(), TOL), ObjectKind::DPlane(d1));
+        assert_eq!(classify(dual.to_ganja(), TOL), ObjectKind::DLine(dual));
+        let direction: DVector<f64> = Vector::new(1.0, -2.0, 0.5).as_direction();
+        assert_eq!(classify(direction.to_ganja(), TOL), ObjectKind::Direction(direction));
+    }
+
+    #[test]
+    fn test_reality() {
+        let (p, q, r) = (point(1.0, 0.0, 0.0), point(-1.0, 0.0, 0.0), point(0.0, 1.0, 0.0));
+        let imaginary = Pair::<f64>::from_mv(p.outer(q).outer(r).into_mv().dual());
+        let kind = ObjectKind::Pair(imaginary, Reality::Imaginary);
+        assert_eq!(classify(imaginary.to_ganja(), TOL), kind);
+
+        let dual = DSphere::<f64>::from_mv(p.into_mv() + Euclidean::infinity() * 2.0);
+        assert_eq!(classify(dual.to_ganja(), TOL), ObjectKind::DSphere(dual, Reality::Imaginary));
+
+        // P∧(P⌋(v∧ni)) is the tangent vector v at P.
+        let v: DVector<f64> = Vector::new(0.0, 1.0, 0.0).as_direction();
+        let tangent = Pair::<f64>::from_mv(p.into_mv() ^ (p.into_mv() | v.into_mv()));
+        let kind = classify(tangent.to_ganja(), TOL);
+        assert!(matches!(kind, ObjectKind::Pair(_, Reality::Tangent)), "{:?}", kind);
+    }
+
+    #[test]
+    fn test_versors() {
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        let t = Vector::new(3.0, -1.0, 0.25).into_translator();
+        let m = Motor::new(r, Vector::new(3.0, -1.0, 0.25));
+        assert_eq!(classify(r.to_ganja(), TOL), ObjectKind::Rotor(r));
+        assert_eq!(classify(t.to_ganja(), TOL), ObjectKind::Translator(t));
+        assert_eq!(classify(m.to_ganja(), TOL), ObjectKind::Motor(m));
+        let d = Dilator::new(2.0);
+        assert_eq!(classify(d.to_ganja(), TOL), ObjectKind::Dilator(d));
+
+        // A reflection in a plane followed by a rotation.
+        let plane: DPlane<f64> = Vector::new(0.0, 0.0, 1.0).normal(1.0);
+        let odd = plane.into_mv() * r.into_mv();
+        assert_eq!(classify(odd.into_array(), TOL), ObjectKind::Versor { odd: true });
+
+        // Noise below the tolerance is ignored, but noise above it is not.
+        let mut c = m.to_ganja();
+        c[1] += 1e-12;
+        assert!(matches!(classify(c, TOL), ObjectKind::Motor(_)));
+        c[1] += 1e-3;
+        assert_eq!(classify(c, TOL), ObjectKind::Mixed);
+
+        let sum = point(1.0, 0.0, 0.0).into_mv() + r.into_mv();
+        assert_eq!(classify(sum.into_array(), TOL), ObjectKind::Mixed);
+    }
+}
@@ -0,0 +1,373 @@
+//! Structure-of-arrays containers for processing many objects at once.
+//!
+//! Each batch stores every component of its object in a separate column. Bulk operations load
+//! [`LANES`] objects at a time into the SIMD type chosen by [`BatchScalar`], and fall back to a
+//! scalar loop for whatever remains at the end of the batch.
+
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+
+use simba::scalar::RealField;
+use simba::simd::SimdValue;
+
+use super::flat::{Line, Plane};
+use super::round::{Pair, Point};
+use super::transform::{Motor, Transform};
+use crate::{Euclidean, Field, Multivec, Outer, Space, R410};
+
+/// The number of objects processed together by a batch operation.
+pub const LANES: usize = 4;
+
+/// A scalar type with a SIMD counterpart used to process batches.
+pub trait BatchScalar: RealField + Copy {
+    /// Holds [`LANES`] copies of `Self`, one per lane.
+    type Lanes: Field<Element = Self> + Copy + From<[Self; LANES]> + Into<[Self; LANES]>;
+}
+
+#[cfg(not(feature = "wide"))]
+impl BatchScalar for f32 {
+    type Lanes = simba::simd::AutoF32x4;
+}
+
+#[cfg(not(feature = "wide"))]
+impl BatchScalar for f64 {
+    type Lanes = simba::simd::AutoF64x4;
+}
+
+#[cfg(feature = "wide")]
+impl BatchScalar for f32 {
+    type Lanes = simba::simd::WideF32x4;
+}
+
+#[cfg(feature = "wide")]
+impl BatchScalar for f64 {
+    type Lanes = simba::simd::WideF64x4;
+}
+
+#[inline]
+fn load<T: BatchScalar>(column: &[T], i: usize) -> T::Lanes {
+    let mut lanes = [T::zero(); LANES];
+    lanes.copy_from_slice(&column[i..i + LANES]);
+    lanes.into()
+}
+
+#[inline]
+fn store<T: BatchScalar>(column: &mut Vec<T>, lanes: T::Lanes) {
+    let lanes: [T; LANES] = lanes.into();
+    column.extend_from_slice(&lanes);
+}
+
+/// Storage that batch operations write their results into.
+trait Columns<T: BatchScalar> {
+    type Item;
+    type Lanes;
+    fn with_capacity(capacity: usize) -> Self;
+    fn push(&mut self, item: Self::Item);
+    fn push_lanes(&mut self, lanes: Self::Lanes);
+}
+
+impl<T: BatchScalar> Columns<T> for Vec<T> {
+    type Item = T;
+    type Lanes = T::Lanes;
+    #[inline]
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+    #[inline]
+    fn push(&mut self, item: T) {
+        Vec::push(self, item)
+    }
+    #[inline]
+    fn push_lanes(&mut self, lanes: T::Lanes) {
+        store(self, lanes)
+    }
+}
+
+/// Evaluates `$body` for every index of the input batches, [`LANES`] objects at a time.
+/// Captured values are splatted across the lanes for the vectorized part.
+macro_rules! batch_map {
+    ($out:ty; [$($x:ident in $batch:expr),+]; [$($cap:ident: $ct:ident$(<$cs:ident>)? = $cv:expr),*]; $body:expr) => {{
+        let len = [$($batch.len()),+][0];
+        $(assert_eq!($batch.len(), len, "Batches differ in length");)+
+        let mut out = <$out as Columns<T>>::with_capacity(len);
+        let mut i = 0;
+        {
+            $(let $cap = $ct::<T::Lanes $(, $cs)?>::splat($cv);)*
+            while i + LANES <= len {
+                $(let $x = $batch.load(i);)+
+                Columns::push_lanes(&mut out, $body);
+                i += LANES;
+            }
+        }
+        $(let $cap = $cv;)*
+        while i < len {
+            $(let $x = $batch.at(i);)+
+            Columns::push(&mut out, $body);
+            i += 1;
+        }
+        out
+    }};
+}
+
+macro_rules! impl_batch {
+    ($(#[$attr:meta])* $batch:ident, $t:ident {$($field:ident),*}) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $batch<T, S = Euclidean> {
+            $($field: Vec<T>,)*
+            _pd: PhantomData<S>,
+        }
+
+        impl<T, S> Default for $batch<T, S> {
+            #[inline]
+            fn default() -> Self {
+                Self { $($field: Vec::new(),)* _pd: PhantomData }
+            }
+        }
+
+        impl<T: BatchScalar, S: Space> $batch<T, S> {
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #[inline]
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self { $($field: Vec::with_capacity(capacity),)* _pd: PhantomData }
+            }
+
+            #[inline]
+            pub fn len(&self) -> usize {
+                [$(self.$field.len()),*][0]
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            #[inline]
+            pub fn clear(&mut self) {
+                $(self.$field.clear();)*
+            }
+
+            #[inline]
+            pub fn push(&mut self, obj: $t<T, S>) {
+                $(self.$field.push(obj.$field);)*
+            }
+
+            /// Returns the object at index `i`, or `None` if it's out of bounds.
+            #[inline]
+            pub fn get(&self, i: usize) -> Option<$t<T, S>> {
+                if i < self.len() {
+                    Some(self.at(i))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = $t<T, S>> + '_ {
+                (0..self.len()).map(move |i| self.at(i))
+            }
+
+            #[inline]
+            fn at(&self, i: usize) -> $t<T, S> {
+                $t { $($field: self.$field[i],)* _pd: PhantomData }
+            }
+
+            /// Loads the `LANES` objects starting at index `i`.
+            #[inline]
+            fn load(&self, i: usize) -> $t<T::Lanes, S> {
+                $t { $($field: load(&self.$field, i),)* _pd: PhantomData }
+            }
+        }
+
+        impl<T: BatchScalar, S: Space> Columns<T> for $batch<T, S> {
+            type Item = $t<T, S>;
+            type Lanes = $t<T::Lanes, S>;
+            #[inline]
+            fn with_capacity(capacity: usize) -> Self {
+                Self::with_capacity(capacity)
+            }
+            #[inline]
+            fn push(&mut self, obj: $t<T, S>) {
+                Self::push(self, obj)
+            }
+            #[inline]
+            fn push_lanes(&mut self, obj: $t<T::Lanes, S>) {
+                $(store(&mut self.$field, obj.$field);)*
+            }
+        }
+
+        impl<T: BatchScalar, S: Space> FromIterator<$t<T, S>> for $batch<T, S> {
+            fn from_iter<I: IntoIterator<Item = $t<T, S>>>(iter: I) -> Self {
+                let mut batch = Self::new();
+                Extend::extend(&mut batch, iter);
+                batch
+            }
+        }
+
+        impl<T: BatchScalar, S: Space> Extend<$t<T, S>> for $batch<T, S> {
+            fn extend<I: IntoIterator<Item = $t<T, S>>>(&mut self, iter: I) {
+                for obj in iter {
+                    self.push(obj);
+                }
+            }
+        }
+    };
+}
+
+impl_batch!(
+    /// A collection of points stored as one column per component.
+    PointBatch, Point {e1, e2, e3, ep, en}
+);
+impl_batch!(
+    /// A collection of point pairs stored as one column per component.
+    PairBatch, Pair {e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn}
+);
+impl_batch!(
+    /// A collection of lines stored as one column per component.
+    LineBatch, Line {e12i, e13i, e23i, e1pn, e2pn, e3pn}
+);
+
+/// The signed distance from a point to a plane, measured along the plane's normal.
+#[inline]
+fn plane_distance<U: Field + Copy, S: Space>(p: Point<U, S>, plane: Plane<U, S>) -> U {
+    let dual: R410<U> = !plane.into_mv();
+    let p = p.into_mv();
+    let weight = -(p | S::infinity()).s;
+    let normal = (dual.e1 * dual.e1 + dual.e2 * dual.e2 + dual.e3 * dual.e3).simd_sqrt();
+    (p | dual).s / (weight * normal)
+}
+
+impl<T: BatchScalar, S: Space> PointBatch<T, S> {
+    /// Computes the signed distance from each point to `plane`.
+    pub fn distance_to(&self, plane: &Plane<T, S>) -> Vec<T> {
+        batch_map!(Vec<T>; [p in self]; [plane: Plane<S> = *plane]; plane_distance(p, plane))
+    }
+
+    /// Joins each point with the point at the same index in `other`.
+    /// Panics if the batches differ in length.
+    pub fn join(&self, other: &Self) -> PairBatch<T, S> {
+        batch_map!(PairBatch<T, S>; [p in self, q in other]; []; p.outer(q))
+    }
+}
+
+impl<T: BatchScalar> PointBatch<T, Euclidean> {
+    /// Applies `motor` to every point.
+    pub fn transform(&self, motor: &Motor<T>) -> Self {
+        batch_map!(Self; [p in self]; [m: Motor = *motor]; m.transform(p))
+    }
+}
+
+impl<T: BatchScalar, S: Space> PairBatch<T, S> {
+    /// Extends each pair into the line passing through both of its points.
+    pub fn extend(&self) -> LineBatch<T, S> {
+        batch_map!(LineBatch<T, S>; [p in self]; []; p.extend())
+    }
+}
+
+impl<T: BatchScalar> PairBatch<T, Euclidean> {
+    /// Applies `motor` to every pair.
+    pub fn transform(&self, motor: &Motor<T>) -> Self {
+        batch_map!(Self; [p in self]; [m: Motor = *motor]; m.transform(p))
+    }
+}
+
+impl<T: BatchScalar> LineBatch<T, Euclidean> {
+    /// Applies `motor` to every line.
+    pub fn transform(&self, motor: &Motor<T>) -> Self {
+        batch_map!(Self; [l in self]; [m: Motor = *motor]; m.transform(l))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::free::{Bivector, Vector};
+    use approx::assert_relative_eq;
+
+    fn assert_close(a: R410<f64>, b: R410<f64>) {
+        for (x, y) in a.into_array().iter().zip(b.into_array().iter()) {
+            assert_relative_eq!(x, y, epsilon = 1e-9);
+        }
+    }
+
+    fn points(n: usize) -> Vec<Point<f64>> {
+        (0..n)
+            .map(|i| {
+                let i = i as f64;
+                Point::new([i, 2.0 - i * 0.5, i * i * 0.25])
+            })
+            .collect()
+    }
+
+    fn motor() -> Motor<f64> {
+        let rotor = Bivector { e12: 1.0, e13: 0.5, e23: -2.0 }.with_angle(0.7);
+        let translator = Vector::new(1.0, -3.0, 2.0).into_translator();
+        Motor::from(translator) * Motor::from(rotor)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ps = points(7);
+        let batch: PointBatch<_> = ps.iter().copied().collect();
+        assert_eq!(batch.len(), 7);
+        assert_eq!(batch.get(7), None);
+        assert_eq!(batch.iter().collect::<Vec<_>>(), ps);
+    }
+
+    #[test]
+    fn test_transform() {
+        let m = motor();
+        // Not a multiple of LANES, so the scalar tail runs as well.
+        let ps = points(11);
+        let batch: PointBatch<_> = ps.iter().copied().collect();
+        for (p, q) in ps.iter().zip(batch.transform(&m).iter()) {
+            let expected = m.transform(*p).into_mv();
+            assert_close(q.into_mv(), expected);
+        }
+
+        let lines = batch.join(&batch.transform(&m)).extend();
+        for (l, t) in lines.iter().zip(lines.transform(&m).iter()) {
+            assert_close(t.into_mv(), m.transform(l).into_mv());
+        }
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let plane = Point::<_>::new([0.0, 0.0, 0.0])
+            .outer(Point::new([1.0, 0.0, 0.0]))
+            .outer(Point::new([0.0, 1.0, 0.0]))
+            .extend();
+        let ps = points(9);
+        let batch: PointBatch<_> = ps.iter().copied().collect();
+        let dist = batch.distance_to(&plane);
+        assert_eq!(dist.len(), 9);
+        for (p, d) in ps.iter().zip(&dist) {
+            assert_relative_eq!(*d, plane.signed_distance(*p), epsilon = 1e-9);
+            assert_relative_eq!(d.abs(), p.into_mv().e3.abs(), epsilon = 1e-9);
+        }
+        let flipped = batch.distance_to(&plane.flip());
+        for (d, f) in dist.iter().zip(flipped) {
+            assert_relative_eq!(*d, -f, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_join() {
+        let ps = points(6);
+        let qs: Vec<_> = points(6).into_iter().rev().collect();
+        let a: PointBatch<_> = ps.iter().copied().collect();
+        let b: PointBatch<_> = qs.iter().copied().collect();
+        let pairs = a.join(&b);
+        for ((p, q), pair) in ps.iter().zip(&qs).zip(pairs.iter()) {
+            assert_eq!(pair, p.outer(*q));
+        }
+        let lines = pairs.extend();
+        for (pair, line) in pairs.iter().zip(lines.iter()) {
+            assert_eq!(line, pair.extend());
+        }
+    }
+}
@@ -8,7 +8,10 @@ use crate::{Euclidean, Field, Multivec, Space, R410};
 
 /// A light-like direction vector
 /// The bivector part of a translator
-#[derive(Debug, Copy, Clone)]
+///
+/// Laid out in memory as `[e1i, e2i, e3i]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct DVector<T, S = Euclidean> {
     e1i: T,
     e2i: T,
@@ -16,6 +19,8 @@ pub struct DVector<T, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(DVector<S>{e1i, e2i, e3i, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> DVector<T, S> {
     /// Converts the direction vector into a translator that moves along the direction the same distance.
     pub fn into_translator(self) -> Translator<T, S> {
@@ -45,6 +50,14 @@ impl<T: Field + Copy, S: Space> Multivec for DVector<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e1p, v.e1n) && S::joins(v.e2p, v.e2n) && S::joins(v.e3p, v.e3n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 { e1p, e1n, e2p, e2n, e3p, e3n, .. } = v;
@@ -3,12 +3,14 @@ use core::marker::PhantomData;
 use num_traits::zero;
 use simba::simd::SimdRealField as Field;
 
-use crate::{Inner, Multivec, R410, Space, Euclidean};
+use crate::{Commutator, Inner, Multivec, R410, Space, Euclidean};
 
 use super::super::flat::{FPoint, Line, Plane};
 use super::super::round::{Pair, Sphere};
 
-#[derive(Copy, Clone, Debug)]
+/// Laid out in memory as `[e12, e13, e23, e1i, e2i, e3i]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct DLine<T, S = Euclidean> {
     pub(crate) e12: T,
     pub(crate) e13: T,
@@ -19,6 +21,8 @@ pub struct DLine<T, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(DLine<S>{e12, e13, e23, e1i, e2i, e3i, [_pd: PhantomData]});
+
 /*
 (a12 + b13 + c23 + d1i + e2i + f3i)(a21 + b31 + c32 + di1 + ei2 + fi3)
 a^2 + b^2 + c^2 0
@@ -59,6 +63,14 @@ impl<T: Field + Copy, S: Space> Multivec for DLine<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e1p, v.e1n) && S::joins(v.e2p, v.e2n) && S::joins(v.e3p, v.e3n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -85,6 +97,11 @@ impl<T: Field + Copy, S: Space> Multivec for DLine<T, S> {
     }
 }
 
+/// The dual lines are closed under the commutator, forming the Lie algebra of motors.
+impl<T: Field + Copy, S: Space> Commutator for DLine<T, S> {
+    type Output = DLine<T, S>;
+}
+
 impl<T: Field + Copy, S: Space> Inner<Sphere<T>> for DLine<T, S> {
     type Output = Pair<T, S>;
 }
@@ -4,10 +4,14 @@ use num_traits::zero;
 use simba::simd::SimdRealField as Field;
 
 use super::super::flat::{FPoint, Line, Plane};
+use super::super::free::Vector;
+use super::super::round::Point;
 use super::super::transform::Transform;
 use crate::{Euclidean, Inner, Multivec, Space, R410};
 
-#[derive(Copy, Clone, Debug)]
+/// Laid out in memory as `[e1, e2, e3, ei]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct DPlane<T, S = Euclidean> {
     pub(crate) e1: T,
     pub(crate) e2: T,
@@ -17,6 +21,8 @@ pub struct DPlane<T, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(DPlane<S>{e1, e2, e3, ei, [_pd: PhantomData]});
+
 /*
     (ep + en) / e123pn = e123n + e123p = e123i
     ep / e123pn = e123n != e123i
@@ -27,6 +33,39 @@ impl<T: Field + Copy, S: Space> DPlane<T, S> {
     pub fn undual(self) -> Plane<T, S::Dual> {
         Plane::from_mv(self.into_mv().undual())
     }
+
+    /// Constructs the plane through `point` perpendicular to `normal`.
+    #[inline]
+    pub fn from_point_normal(point: Point<T, S>, normal: Vector<T>) -> Self {
+        let p = point.into_mv();
+        normal.normal(-(p | normal.into_mv()).s / (p | S::infinity()).s)
+    }
+
+    /// The normal `n` of the Hessian form `n·x = d`.
+    #[inline]
+    pub fn normal(self) -> Vector<T> {
+        Vector::new(self.e1, self.e2, self.e3)
+    }
+
+    /// The offset `d` of the Hessian form `n·x = d`, which is the distance from the origin when
+    /// the normal is a unit vector.
+    #[inline]
+    pub fn offset(self) -> T {
+        self.ei
+    }
+
+    /// The distance from the plane to `p`, positive on the side the normal points to.
+    #[inline]
+    pub fn signed_distance(self, p: Point<T, S>) -> T {
+        let p = p.into_mv();
+        (p | self.into_mv()).s / -(p | S::infinity()).s / self.normal().norm()
+    }
+
+    /// The same plane facing the opposite direction.
+    #[inline]
+    pub fn flip(self) -> Self {
+        Self::from_mv(-self.into_mv())
+    }
 }
 
 impl<T: Field + Copy, S: Space> Multivec for DPlane<T, S> {
@@ -44,6 +83,14 @@ impl<T: Field + Copy, S: Space> Multivec for DPlane<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.ep, v.en)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -63,3 +110,48 @@ impl<T: Field + Copy, S: Space> Inner<Line<T, S>> for DPlane<T, S> {
     type Output = FPoint<T, S>;
 }
 impl<T: Field + Copy, S: Space> Transform<Line<T, S>> for DPlane<T, S> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::free::Vector;
+    use crate::d3::round::Point;
+    use crate::Outer;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_reflect_line() {
+        let mirror: DPlane<f64> = Vector::new(1.0, 0.0, 0.0).normal(0.0);
+        let p1 = Point::<f64>::new([1.0, 0.0, 0.0]);
+        let p2 = Point::<f64>::new([2.0, 1.0, 3.0]);
+        let q1 = Point::<f64>::new([-1.0, 0.0, 0.0]);
+        let q2 = Point::<f64>::new([-2.0, 1.0, 3.0]);
+
+        let reflected = mirror.transform(p1.outer(p2).extend()).into_mv();
+        let expected = q1.outer(q2).extend().into_mv();
+        for (a, b) in reflected.into_array().iter().zip(expected.into_array().iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hessian() {
+        let n = Vector::new(2.0, -1.0, 2.0);
+        let plane: DPlane<f64> = n.normal(6.0);
+        assert_eq!(plane.normal(), n);
+        assert_eq!(plane.offset(), 6.0);
+
+        let p = Point::new([1.0, -2.0, 1.0]);
+        let through = DPlane::from_point_normal(p, n);
+        assert_relative_eq!(through, plane, epsilon = 1e-12);
+        assert_relative_eq!(through.signed_distance(p), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(plane.signed_distance(Point::new([0.0; 3])), -2.0, epsilon = 1e-12);
+        let q = Point::new([3.0, -3.0, 3.0]);
+        assert_relative_eq!(plane.signed_distance(q), 3.0, epsilon = 1e-12);
+
+        let flipped = plane.flip();
+        assert_eq!(flipped.normal(), -n);
+        assert_eq!(flipped.offset(), -6.0);
+        assert_relative_eq!(flipped.signed_distance(Point::new([0.0; 3])), 2.0, epsilon = 1e-12);
+    }
+}
@@ -1,13 +1,16 @@
 use core::marker::PhantomData;
 
 use num_traits::zero;
+use simba::scalar::RealField;
 use simba::simd::SimdRealField as Field;
 
 use super::super::flat::Line;
 use super::super::round::{Circle, Pair, Sphere, Point};
-use crate::{Inner, Multivec, Scalar, R410, Space, Euclidean};
+use crate::{Error, Inner, Multivec, Scalar, R410, Space, Euclidean};
 
-#[derive(Copy, Clone, Debug)]
+/// Laid out in memory as `[e1, e2, e3, ep, en]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct DSphere<T, S = Euclidean> {
     pub(crate) e1: T,
     pub(crate) e2: T,
@@ -17,6 +20,8 @@ pub struct DSphere<T, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(DSphere<S>{e1, e2, e3, ep, en, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> DSphere<T, S> {
     /// Converts the dual sphere to standard form.
     pub fn undual(self) -> Sphere<T, S::Dual> {
@@ -31,9 +36,19 @@ impl<T: Field + Copy, S: Space> DSphere<T, S> {
         self.inner(self).0.simd_sqrt()
     }
 
+    /// The center of the sphere, with a weight of one.
     pub fn center(self) -> Point<T, S> {
+        let ni = S::infinity();
         let s = self.into_mv();
-        Point::from_mv(s * S::infinity() * s)
+        let p = s * ni * s;
+        Point::from_mv(p / -(p | ni))
+    }
+}
+
+impl<T: RealField + Copy, S: Space> DSphere<T, S> {
+    /// The radius of the sphere, or `Error::Imaginary` if the sphere is imaginary.
+    pub fn try_radius(self) -> Result<T, Error> {
+        self.inner(self).0.try_sqrt().ok_or(Error::Imaginary)
     }
 }
 
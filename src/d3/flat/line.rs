@@ -3,17 +3,19 @@ use core::marker::PhantomData;
 
 use num_traits::{one, zero};
 
-use simba::scalar::{SubsetOf, SupersetOf};
 
-use crate::{Dual, Field, Inner, Multivec, R410, Space, Euclidean};
+use crate::{Commutator, Dual, Field, Inner, Multivec, Normalize, Outer, R410, Space, Euclidean};
 
 use super::super::dual::DLine;
 use super::super::direction::DVector;
 use super::super::free::Vector;
 use super::super::transform::Motor;
 use super::super::Point;
+use super::FPoint;
 
+/// Laid out in memory as `[e12i, e13i, e23i, e1pn, e2pn, e3pn]`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Line<T, S = Euclidean> {
     /// Corresponds to both e12p and e12n
     pub(crate) e12i: T,
@@ -24,7 +26,7 @@ pub struct Line<T, S = Euclidean> {
     pub(crate) e1pn: T,
     pub(crate) e2pn: T,
     pub(crate) e3pn: T,
-    _pd: PhantomData<S>,
+    pub(crate) _pd: PhantomData<S>,
 }
 
 /*
@@ -32,7 +34,7 @@ L = vx eo1 + vy eo2 + vz eo3 + mx e23 + my e31 + mz e12
 L ^ ei
 */
 
-impl_traits!(Line{e12i, e13i, e23i, e1pn, e2pn, e3pn, [_pd: PhantomData]});
+impl_traits!(Line<S>{e12i, e13i, e23i, e1pn, e2pn, e3pn, [_pd: PhantomData]});
 
 impl<T: Field + Copy, S: Space> Multivec for Line<T, S> {
     type Element = T;
@@ -61,6 +63,14 @@ impl<T: Field + Copy, S: Space> Multivec for Line<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e12p, v.e12n) && S::joins(v.e13p, v.e13n) && S::joins(v.e23p, v.e23n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -88,6 +98,12 @@ impl<T: Field + Copy, S: Space> Multivec for Line<T, S> {
 }
 
 impl<T: Field + Copy, S: Space> Line<T, S> {
+    /// Constructs the line through two flat points, directed from `a` to `b`.
+    #[inline]
+    pub fn through(a: FPoint<T, S>, b: FPoint<T, S>) -> Self {
+        a.into_point().outer(b.normalized())
+    }
+
     #[inline]
     pub fn into_vector(self) -> Vector<T> {
         let mink = R410 {
@@ -109,11 +125,96 @@ impl<T: Field + Copy, S: Space> Line<T, S> {
         if p.inner(Point::ni()).0.is_zero() {
             None
         } else {
-            Some(-(self.into_vector().into_mv() | p.normalize().into_mv()).s)
+            Some((self.into_vector().into_mv() | p.normalized().into_mv()).s)
         }
     }
 }
 
+/// The cross product of two Euclidean vectors.
+#[inline]
+fn cross<T: Field + Copy>(a: Vector<T>, b: Vector<T>) -> Vector<T> {
+    Vector::new(
+        a.e2 * b.e3 - a.e3 * b.e2,
+        a.e3 * b.e1 - a.e1 * b.e3,
+        a.e1 * b.e2 - a.e2 * b.e1,
+    )
+}
+
+/// Plücker coordinates, where a line through `p` with direction `d` has the moment `m = p × d`.
+impl<T: Field + Copy> Line<T> {
+    /// Constructs the line with the given direction and moment. The moment must be orthogonal to
+    /// the direction.
+    #[inline]
+    pub fn from_plucker(direction: Vector<T>, moment: Vector<T>) -> Self {
+        Self {
+            e12i: moment.e3,
+            e13i: -moment.e2,
+            e23i: moment.e1,
+            e1pn: direction.e1,
+            e2pn: direction.e2,
+            e3pn: direction.e3,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Constructs the line passing through `point` along `direction`.
+    #[inline]
+    pub fn from_point_direction(
+        point: impl Into<Vector<T>>,
+        direction: impl Into<Vector<T>>,
+    ) -> Self {
+        let direction = direction.into();
+        Self::from_plucker(direction, cross(point.into(), direction))
+    }
+
+    /// Constructs the line where the planes `n1·x = d1` and `n2·x = d2` meet, directed along
+    /// `n1 × n2`. Parallel planes give a line at infinity.
+    #[inline]
+    pub fn from_planes(n1: impl Into<Vector<T>>, d1: T, n2: impl Into<Vector<T>>, d2: T) -> Self {
+        let (n1, n2) = (n1.into(), n2.into());
+        Self::from_plucker(cross(n1, n2), n1 * d2 - n2 * d1)
+    }
+
+    /// Splits the line into its direction and moment.
+    #[inline]
+    pub fn to_plucker(self) -> (Vector<T>, Vector<T>) {
+        (Vector::new(self.e1pn, self.e2pn, self.e3pn), self.moment())
+    }
+
+    /// The moment `p × d` of the line about the origin.
+    #[inline]
+    pub fn moment(self) -> Vector<T> {
+        Vector::new(self.e23i, -self.e13i, self.e12i)
+    }
+
+    /// The point `d × m / |d|²` of the line closest to the origin.
+    ///
+    /// A line at infinity, like the meet of two parallel planes, has `d = 0` and no finite
+    /// points, so this and the other point queries below give non-finite coordinates for it.
+    #[inline]
+    pub fn closest_point_to_origin(self) -> Point<T> {
+        let (d, m) = self.to_plucker();
+        Point::new(cross(d, m) / d.norm_squared())
+    }
+
+    /// The point `t` times the direction away from the point closest to the origin. Not finite
+    /// for a line at infinity.
+    #[inline]
+    pub fn point_at(self, t: T) -> Point<T> {
+        let (d, m) = self.to_plucker();
+        Point::new((cross(d, m) / d.norm_squared()) + d * t)
+    }
+
+    /// The orthogonal projection of `p` onto the line, or non-finite for a line at infinity.
+    #[inline]
+    pub fn closest_point(self, p: Point<T>) -> Point<T> {
+        let (d, m) = self.to_plucker();
+        let norm_squared = d.norm_squared();
+        let origin = cross(d, m) / norm_squared;
+        Point::new(origin + d * ((p.position() - origin).dot(d) / norm_squared))
+    }
+}
+
 impl<T: Field + Copy, S: Space> Dual for Line<T, S> {
     type Output = DLine<T, S::Dual>;
     /*
@@ -131,6 +232,11 @@ impl<T: Field + Copy, S: Space> Dual for Line<T, S> {
     */
 }
 
+/// The bivector part of the motor `self * rhs`.
+impl<T: Field + Copy, S: Space> Commutator for Line<T, S> {
+    type Output = DLine<T, S::Dual>;
+}
+
 impl<T: Field + Copy, S: Space> Mul for Line<T, S> {
     type Output = Motor<T>;
     fn mul(self, rhs: Self) -> Motor<T> {
@@ -159,7 +265,8 @@ mod test {
     use super::*;
     use crate::d3::free::Vector;
     use crate::d3::transform::Transform;
-    use crate::{Outer, Euclidean};
+    use crate::Euclidean;
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn test_position_on() {
@@ -211,4 +318,69 @@ mod test {
         assert_eq!(l2.position_on(Point::no()), Some(-0.0));
         assert_eq!(l.position_on(Point::no()), Some(-0.0));
     }
+
+    #[test]
+    fn test_commutator() {
+        use crate::Hyperbolic;
+
+        let p1: Point<f64> = Point::new([1.0, 0.0, 0.0]);
+        let p2: Point<f64> = Point::new([3.0, 4.0, 5.0]);
+        let p3: Point<f64> = Point::new([3.0, 4.0, 0.0]);
+
+        let l1 = p1.outer(p2).extend();
+        let l2 = p1.outer(p3).extend();
+        assert_eq!(
+            l1.commutator(l2).into_mv(),
+            l1.into_mv().commutator(l2.into_mv())
+        );
+
+        let h1: Point<f64, Hyperbolic> = Point::new([1.0, 0.0, 0.0]);
+        let h2: Point<f64, Hyperbolic> = Point::new([3.0, 4.0, 5.0]);
+        let h3: Point<f64, Hyperbolic> = Point::new([3.0, 4.0, 0.0]);
+
+        let l1 = h1.outer(h2).extend();
+        let l2 = h1.outer(h3).extend();
+        assert_eq!(
+            l1.commutator(l2).into_mv(),
+            l1.into_mv().commutator(l2.into_mv())
+        );
+    }
+
+    #[test]
+    fn test_plucker() {
+        let d = Vector::new(1.0, 2.0, 2.0);
+        let l = Line::from_point_direction([3.0, 0.0, -1.0], d);
+        let (direction, moment) = l.to_plucker();
+        assert_eq!(direction, d);
+        assert_eq!(moment, Vector::new(2.0, -7.0, 6.0));
+        assert_eq!(moment, l.moment());
+        assert_eq!(Line::from_plucker(direction, moment), l);
+
+        let p: Point<f64> = Point::new([3.0, 0.0, -1.0]);
+        let q: Point<f64> = Point::new([4.0, 2.0, 1.0]);
+        assert_eq!(p.outer(q).extend(), l);
+
+        let closest = l.closest_point_to_origin();
+        assert_abs_diff_eq!(closest.position().dot(d), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(l.closest_point(Point::new([0.0; 3])), closest, epsilon = 1e-12);
+        assert_abs_diff_eq!(l.closest_point(p), p, epsilon = 1e-12);
+        assert_abs_diff_eq!(l.closest_point(Point::new([4.0, 2.0, 1.0])), q, epsilon = 1e-12);
+        assert_abs_diff_eq!(l.point_at(1.0), Point::new(closest.position() + d), epsilon = 1e-12);
+
+        let off = Point::new(q.position() + Vector::new(2.0, -1.0, 0.0));
+        assert_abs_diff_eq!(l.closest_point(off), q, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_from_planes() {
+        // x = 1 and z = 2 meet in a line parallel to the y axis.
+        let l = Line::from_planes(Vector::new(1.0, 0.0, 0.0), 1.0, Vector::new(0.0, 0.0, 1.0), 2.0);
+        let (direction, _) = l.to_plucker();
+        assert_eq!(direction, Vector::new(0.0, -1.0, 0.0));
+        assert_abs_diff_eq!(
+            l.closest_point_to_origin(),
+            Point::new([1.0, 0.0, 2.0]),
+            epsilon = 1e-12
+        );
+    }
 }
@@ -4,11 +4,14 @@ use num_traits::zero;
 use simba::simd::SimdRealField as Field;
 
 use super::super::dual::DPlane;
-use super::super::round::Sphere;
-use crate::{Dual, Euclidean, Multivec, Space, R410};
+use super::super::free::Vector;
+use super::super::round::{Point, Sphere};
+use crate::{Dual, Euclidean, Multivec, Outer, Space, R410};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Plane<T: Field, S = Euclidean> {
+/// Laid out in memory as `[e123i, e12pn, e13pn, e23pn]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Plane<T, S = Euclidean> {
     /// Corresponds to both e123p and e123n
     pub(crate) e123i: T,
     pub(crate) e12pn: T,
@@ -17,11 +20,46 @@ pub struct Plane<T: Field, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(Plane<S>{e123i, e12pn, e13pn, e23pn, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> Plane<T, S> {
     /// Interprets the plane as a sphere with infinite radius
-    pub fn to_round(self) -> Sphere<T> {
+    pub fn to_round(self) -> Sphere<T, S> {
         Sphere::from_mv(self.into_mv())
     }
+
+    /// Constructs the plane through three points, oriented so that they wind counterclockwise
+    /// when seen from the side its normal points to.
+    #[inline]
+    pub fn through(p1: Point<T, S>, p2: Point<T, S>, p3: Point<T, S>) -> Self {
+        p1.outer(p2).outer(p3).extend()
+    }
+
+    /// The normal `n` of the Hessian form `n·x = d`.
+    #[inline]
+    pub fn normal(self) -> Vector<T> {
+        self.dual().normal()
+    }
+
+    /// The offset `d` of the Hessian form `n·x = d`.
+    #[inline]
+    pub fn offset(self) -> T {
+        self.dual().offset()
+    }
+
+    /// The distance from the plane to `p`, positive on the side the normal points to.
+    #[inline]
+    pub fn signed_distance(self, p: Point<T, S>) -> T {
+        let p = p.into_mv();
+        let normal = self.normal();
+        (p | self.into_mv().dual()).s / -(p | S::infinity()).s / normal.norm()
+    }
+
+    /// The same plane facing the opposite direction.
+    #[inline]
+    pub fn flip(self) -> Self {
+        Self::from_mv(-self.into_mv())
+    }
 }
 
 impl<T: Field + Copy, S: Space> Multivec for Plane<T, S> {
@@ -45,6 +83,14 @@ impl<T: Field + Copy, S: Space> Multivec for Plane<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e123p, v.e123n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -68,3 +114,30 @@ impl<T: Field + Copy, S: Space> Multivec for Plane<T, S> {
 impl<T: Field + Copy, S: Space> Dual for Plane<T, S> {
     type Output = DPlane<T, S::Dual>;
 }
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_through() {
+        let p1: Point<f64> = Point::new([1.0, 0.0, 2.0]);
+        let p2 = Point::new([0.0, 1.0, 2.0]);
+        let p3 = Point::new([0.0, 0.0, 2.0]);
+        let plane = Plane::through(p1, p2, p3);
+        let n = plane.normal();
+        assert_relative_eq!(n / n.norm(), Vector::new(0.0, 0.0, 1.0), epsilon = 1e-12);
+        assert_relative_eq!(plane.offset() / n.norm(), 2.0, epsilon = 1e-12);
+        let q = Point::new([5.0, 3.0, 7.0]);
+        assert_relative_eq!(plane.signed_distance(q), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(plane.flip().normal(), -n, epsilon = 1e-12);
+
+        // Round trip through the Hessian form.
+        let dual: DPlane<f64> = n.normal(plane.offset());
+        assert_relative_eq!(dual, plane.dual(), epsilon = 1e-12);
+        assert_relative_eq!(DPlane::from_point_normal(p1, n), dual, epsilon = 1e-12);
+        assert_relative_eq!(dual.signed_distance(p2), 0.0, epsilon = 1e-12);
+    }
+}
@@ -2,13 +2,17 @@ use core::marker::PhantomData;
 use core::ops::Mul;
 
 use num_traits::zero;
+use simba::scalar::RealField;
 
 use super::super::free::Vector;
+use super::super::round::Point;
 use super::super::transform::Translator;
 use super::Line;
-use crate::{Euclidean, Field, Multivec, Space, R410};
+use crate::{Error, Euclidean, Field, Multivec, Normalize, Space, R410};
 
-#[derive(Copy, Clone, Debug)]
+/// Laid out in memory as `[e1i, e2i, e3i, epn]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct FPoint<T, S = Euclidean> {
     /// Corresponds to both e1p and e1n
     pub(crate) e1i: T,
@@ -20,6 +24,8 @@ pub struct FPoint<T, S = Euclidean> {
     pub(crate) _pd: PhantomData<S>,
 }
 
+impl_traits!(FPoint<S>{e1i, e2i, e3i, epn, [_pd: PhantomData]});
+
 /*
 
 (a e1i + b e2i + c e3i + d ei^eo)(a e1i + b e2i + c e3i + d ei^eo)
@@ -51,6 +57,14 @@ impl<T: Field + Copy, S: Space> Multivec for FPoint<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e1p, v.e1n) && S::joins(v.e2p, v.e2n) && S::joins(v.e3p, v.e3n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -74,6 +88,30 @@ impl<T: Field + Copy, S: Space> Multivec for FPoint<T, S> {
 }
 
 impl<T: Field + Copy, S: Space> FPoint<T, S> {
+    /// The coordinates of the flat point, independent of its weight. Named after
+    /// [`Point::position`], since [`ObjectParams::location`](crate::ObjectParams::location) already
+    /// returns the round point.
+    ///
+    /// A direction has no weight, so its coordinates aren't finite; see
+    /// [`FPoint::try_position`].
+    #[inline]
+    pub fn position(self) -> Vector<T> {
+        Vector::new(self.e1i, self.e2i, self.e3i) / self.weight()
+    }
+
+    /// Converts the flat point back into the round point at the same location.
+    #[inline]
+    pub fn into_point(self) -> Point<T, S> {
+        Point::new(self.position())
+    }
+
+    /// The normalized flat point halfway between `self` and `other`.
+    #[inline]
+    pub fn midpoint(self, other: Self) -> Self {
+        let half = T::from_subset(&0.5);
+        Point::new((self.position() + other.position()) * half).into_flat()
+    }
+
     /// Constructs the line along `dir` that passes through self.
     #[inline]
     pub fn extend_along_vec(self, dir: Vector<T>) -> Line<T> {
@@ -81,6 +119,18 @@ impl<T: Field + Copy, S: Space> FPoint<T, S> {
     }
 }
 
+impl<T: RealField + Copy, S: Space> FPoint<T, S> {
+    /// The coordinates of the flat point, or `Error::PointAtInfinity` if it's a direction.
+    #[inline]
+    pub fn try_position(self) -> Result<Vector<T>, Error> {
+        if self.weight().is_zero() {
+            Err(Error::PointAtInfinity)
+        } else {
+            Ok(self.position())
+        }
+    }
+}
+
 impl<T: Field + Copy, S: Space> Mul for FPoint<T, S> {
     type Output = Translator<T>;
     /// Constructs the translator the brings an object twice the distance from `rhs` to `self`
@@ -91,9 +141,11 @@ impl<T: Field + Copy, S: Space> Mul for FPoint<T, S> {
 
 #[cfg(test)]
 mod test {
+    use approx::assert_abs_diff_eq;
+
     use super::*;
     use crate::d3::Point;
-    use crate::Outer;
+    use crate::{Normalize, Outer};
     #[test]
     fn test_extend() {
         let d = Vector::new(-1.0, 1.0, -4.0);
@@ -108,4 +160,36 @@ mod test {
         assert_eq!(p2.into_flat().extend_along_vec(-d), l2);
         assert_eq!(p1.into_flat().extend_along_vec(-d), l2);
     }
+
+    #[test]
+    fn test_conversions() {
+        let v = Vector::new(1.0, 2.0, 3.0);
+        let f = Point::<f64>::new(v).into_flat();
+        assert_eq!(f.weight(), 1.0);
+        assert_eq!(f.position(), v);
+        assert_eq!(f.into_point(), Point::new(v));
+
+        let scaled = FPoint::<f64>::from_mv(f.into_mv() * -2.0);
+        assert_eq!(scaled.weight(), -2.0);
+        assert_eq!(scaled.position(), v);
+        assert_eq!(scaled.normalized(), f);
+        assert_eq!(scaled.try_position(), Ok(v));
+
+        let direction = FPoint::<f64> { epn: 0.0, ..f };
+        assert_eq!(direction.try_position(), Err(Error::PointAtInfinity));
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let a = Point::<f64>::new([1.0, 2.0, 4.0]).into_flat();
+        let b = FPoint::from_mv(Point::<f64>::new([3.0, -2.0, 0.0]).into_flat().into_mv() * 3.0);
+        assert_eq!(a.midpoint(b), Point::new([2.0, 0.0, 2.0]).into_flat());
+
+        // The line runs from `a` to `b`, and swapping them reverses it.
+        let d = (b.position() - a.position()).normalize();
+        let (forward, _) = Line::through(a, b).to_plucker();
+        let (backward, _) = Line::through(b, a).to_plucker();
+        assert_abs_diff_eq!(forward.normalize(), d, epsilon = 1e-12);
+        assert_abs_diff_eq!(backward.normalize(), -d, epsilon = 1e-12);
+    }
 }
@@ -6,15 +6,19 @@ use simba::simd::SimdRealField as Field;
 
 use super::super::transform::Rotor;
 use super::{Trivector, Vector};
-use crate::{Inner, Multivec, Outer, Scalar, R410};
+use crate::{AntiCommutator, Commutator, Inner, Multivec, Outer, Scalar, R410};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Bivector<T: Field> {
+/// Laid out in memory as `[e12, e13, e23]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Bivector<T> {
     pub(crate) e12: T,
     pub(crate) e13: T,
     pub(crate) e23: T,
 }
 
+impl_traits!(Bivector{e12, e13, e23, []});
+
 impl<T: Field + Copy> Multivec for Bivector<T> {
     type Element = T;
     #[inline]
@@ -56,13 +60,15 @@ impl<T: Field + Copy> Bivector<T> {
     }
 
     /// Constructs a rotor that rotates within this plane by the given angle.
+    /// Positive angles turn the first basis vector of the plane toward the second, so `e12`
+    /// rotates `e1` toward `e2`.
     #[inline]
     pub fn with_angle(self, angle: T) -> Rotor<T> {
         let half_angle = angle * T::from_subset(&-0.5);
         (self.normalized() * half_angle).exp()
     }
 
-    /// Computes e^self. Has the same effect as converting to a rotor with twice the angle.
+    /// Computes e^self. Has the same effect as converting to a rotor with twice the negated angle.
     #[inline]
     pub fn exp(self) -> Rotor<T> {
         let bv = self.into_mv();
@@ -75,6 +81,20 @@ impl<T: Field + Copy> Inner for Bivector<T> {
     type Output = Scalar<T>;
 }
 
+/// The bivectors are closed under the commutator, forming the Lie algebra of rotations.
+impl<T: Field + Copy> Commutator for Bivector<T> {
+    type Output = Bivector<T>;
+}
+
+/// The rate of change of a vector under the rotation generated by this bivector.
+impl<T: Field + Copy> Commutator<Vector<T>> for Bivector<T> {
+    type Output = Vector<T>;
+}
+
+impl<T: Field + Copy> AntiCommutator for Bivector<T> {
+    type Output = Scalar<T>;
+}
+
 impl<T: Field + Copy> Outer<Vector<T>> for Bivector<T> {
     type Output = Trivector<T>;
     fn outer(self, rhs: Vector<T>) -> Trivector<T> {
@@ -188,4 +208,30 @@ mod test {
         };
         assert_eq!(zero.norm(), 0.0);
     }
+
+    #[test]
+    pub fn commutator() {
+        use crate::{AntiCommutator, Commutator};
+        use super::Vector;
+
+        let e12 = Bivector {
+            e12: 1.0,
+            e13: 0.0,
+            e23: 0.0,
+        };
+        let e23 = Bivector {
+            e12: 0.0,
+            e13: 0.0,
+            e23: 1.0,
+        };
+
+        let c = e12.commutator(e23);
+        assert_eq!((c.e12, c.e13, c.e23), (0.0, 1.0, 0.0));
+        assert_eq!(e12.anticommutator(e23).0, 0.0);
+        assert_eq!(e12.anticommutator(e12).0, -1.0);
+
+        let v = e12.commutator(Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(v, Vector::new(0.0, -1.0, 0.0));
+    }
 }
+
@@ -2,11 +2,15 @@ use num_traits::zero;
 
 use crate::{Field, Inner, Multivec, Scalar, R410};
 
-#[derive(Copy, Clone)]
+/// Laid out in memory as `[e123]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Trivector<T> {
     pub(crate) e123: T,
 }
 
+impl_traits!(Trivector{e123, []});
+
 impl<T: Field + Copy> Inner for Trivector<T> {
     type Output = Scalar<T>;
 }
@@ -1,11 +1,5 @@
-use core::fmt::{self, Display, Formatter, LowerExp, UpperExp};
 use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Sub};
 
-use simba::scalar::{SubsetOf, SupersetOf};
-#[cfg(feature = "nalgebra")]
-use simba::simd::SimdBool;
-use simba::simd::SimdValue;
-
 use num_traits::{one, zero, Zero};
 
 use super::super::direction::DVector;
@@ -13,9 +7,11 @@ use super::super::dual::DPlane;
 use super::super::round::{Point, origin};
 use super::super::transform::{Rotor, Translator};
 use super::Bivector;
-use crate::{Field, Inner, Multivec, Outer, Reflect, Scalar, Space, R410};
+use crate::{AntiCommutator, Commutator, Error, Field, Inner, Multivec, Outer, Reflect, Scalar, Space, R410};
 
+/// Laid out in memory as `[e1, e2, e3]`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Vector<T> {
     pub(crate) e1: T,
     pub(crate) e2: T,
@@ -128,6 +124,17 @@ impl<T: Field + Copy> Vector<T> {
         }
     }
 
+    /// Scales the vector to unit length, or fails with `Error::Degenerate` for the zero vector.
+    #[inline]
+    pub fn try_normalize(self) -> Result<Self, Error> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            Err(Error::Degenerate)
+        } else {
+            Ok(Self::from_mv(self.into_mv() / norm))
+        }
+    }
+
     #[inline]
     pub fn norm(self) -> T {
         self.into_mv().norm()
@@ -143,24 +150,6 @@ impl<T: Field + Copy> Vector<T> {
     }
 }
 
-impl<T: Field + Copy + Display> Display for Vector<T> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Vector({})", self.into_mv())
-    }
-}
-
-impl<T: Field + Copy + LowerExp> LowerExp for Vector<T> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Vector({:e})", self.into_mv())
-    }
-}
-
-impl<T: Field + Copy + UpperExp> UpperExp for Vector<T> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Vector({:E})", self.into_mv())
-    }
-}
-
 impl<T: Field + Copy> Inner for Vector<T> {
     type Output = Scalar<T>;
     /*
@@ -183,6 +172,14 @@ impl<T: Field + Copy> Outer for Vector<T> {
     }
 }
 
+impl<T: Field + Copy> Commutator for Vector<T> {
+    type Output = Bivector<T>;
+}
+
+impl<T: Field + Copy> AntiCommutator for Vector<T> {
+    type Output = Scalar<T>;
+}
+
 impl<T: Field + Copy> Reflect<Self> for Vector<T> {
     #[inline]
     fn reflect(self, object: Self) -> Self {
@@ -275,9 +272,7 @@ impl<T: Field + Copy> nalgebra::Normed for Vector<T> {
     type Norm = T;
     #[inline]
     fn norm(&self) -> T {
-        let n = self.into_mv().norm_squared();
-        assert!(n.is_simd_positive().all(), "Degenerate vector: {:?}", n);
-        n.simd_sqrt()
+        self.into_mv().norm_squared().simd_sqrt()
     }
     #[inline]
     fn norm_squared(&self) -> T {
@@ -287,9 +282,10 @@ impl<T: Field + Copy> nalgebra::Normed for Vector<T> {
     fn scale_mut(&mut self, n: T) {
         *self *= n
     }
+    /// Like nalgebra's own vectors, dividing by a zero norm gives non-finite components;
+    /// `Unit::try_new` and [`Vector::try_normalize`] check for it first.
     #[inline]
     fn unscale_mut(&mut self, n: T) {
-        assert!(!n.is_zero(), "Zero norm: {:?} (vec: {:?})", n, self);
         *self /= n
     }
 }
@@ -318,4 +314,16 @@ mod test {
         assert!((p1 + p3).norm() <= p1.norm() + p3.norm());
         assert!((p2 + p3).norm() <= p2.norm() + p3.norm());
     }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_normed() {
+        use nalgebra::{Normed, Unit};
+
+        let o = Vector::new(0.0, 0.0, 0.0);
+        assert_eq!(Normed::norm(&o), 0.0);
+        assert!(Unit::try_new(o, 0.0).is_none());
+        let u = Unit::new_normalize(Vector::new(0.0, 0.0, 2.0));
+        assert_eq!(u.into_inner(), Vector::new(0.0, 0.0, 1.0));
+    }
 }
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod direction;
 pub mod dual;
 pub mod flat;
@@ -7,5 +8,5 @@ pub mod round;
 pub mod transform;
 
 pub use flat::{Line, Plane};
-pub use round::{Circle, Pair, Point, Sphere};
+pub use round::{Circle, Pair, PairDecomposition, Point, Sphere};
 pub use transform::Transform;
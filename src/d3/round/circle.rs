@@ -3,13 +3,15 @@ use core::marker::PhantomData;
 use num_traits::zero;
 
 
-use super::super::dual::DLine;
+use super::super::dual::{DLine, DSphere};
 use super::super::flat::Plane;
-use super::{Point, Sphere};
+use super::{Pair, Point, Sphere};
 use crate::{Field, Multivec, Outer, R410, Space, Euclidean};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Circle<T: Field, S = Euclidean> {
+/// Laid out in memory as `[e123, e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Circle<T, S = Euclidean> {
     pub(crate) e123: T,
     pub(crate) e12p: T,
     pub(crate) e12n: T,
@@ -23,6 +25,8 @@ pub struct Circle<T: Field, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(Circle<S>{e123, e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> Circle<T, S> {
     /// Extends the circle into the infinite plane containing it.
     pub fn extend(self) -> Plane<T, S> {
@@ -33,6 +37,24 @@ impl<T: Field + Copy, S: Space> Circle<T, S> {
     pub fn axis(self) -> DLine<T, S> {
         DLine::from_mv(S::infinity() | self.into_mv())
     }
+
+    /// The smallest flat containing the circle, which is the plane it lies in.
+    #[inline]
+    pub fn carrier(self) -> Plane<T, S> {
+        self.extend()
+    }
+
+    /// Constructs the dual form of the sphere with the same center and radius as the circle.
+    #[inline]
+    pub fn surround(self) -> DSphere<T, S> {
+        super::surround(self.into_mv())
+    }
+
+    /// The tangent vector of the circle at a point on it, as a pair of zero size.
+    #[inline]
+    pub fn tangent_at(self, p: Point<T, S>) -> Pair<T, S> {
+        Pair::from_mv(super::tangent_at(self.into_mv(), p))
+    }
 }
 
 /*
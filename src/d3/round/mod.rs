@@ -4,6 +4,81 @@ mod point;
 mod sphere;
 
 pub use circle::Circle;
-pub use pair::Pair;
+pub use pair::{Pair, PairDecomposition};
 pub use point::{Point, origin};
 pub use sphere::Sphere;
+
+use super::dual::DSphere;
+use crate::{Field, Multivec, Space, R410};
+
+/// The dual sphere `X (X∧ni)⁻¹` sharing the center and radius of the round `X`.
+#[inline]
+fn surround<T: Field + Copy, S: Space>(x: R410<T>) -> DSphere<T, S> {
+    let carrier = x ^ S::infinity();
+    DSphere::from_mv(x * carrier / (carrier * carrier).s)
+}
+
+/// The tangent `p⌋X` of the round `X` at the point `p`, one grade lower than `X`.
+#[inline]
+fn tangent_at<T: Field + Copy, S: Space>(x: R410<T>, p: Point<T, S>) -> R410<T> {
+    p.into_mv() | x
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{Normalize, ObjectParams, Outer};
+
+    fn point(x: f64, y: f64, z: f64) -> Point<f64> {
+        Point::new([x, y, z])
+    }
+
+    #[test]
+    fn test_surround() {
+        let pair = point(1.0, 2.0, 0.0).outer(point(1.0, 2.0, 4.0));
+        let circle = point(2.0, 0.0, 3.0)
+            .outer(point(0.0, 2.0, 3.0))
+            .outer(point(-2.0, 0.0, 3.0));
+        let sphere = circle.outer(point(0.0, 0.0, 5.0));
+
+        let s = pair.surround().normalized();
+        assert_relative_eq!(s.center(), point(1.0, 2.0, 2.0), epsilon = 1e-12);
+        assert_relative_eq!(s.radius(), 2.0, epsilon = 1e-12);
+        for s in [circle.surround().normalized(), sphere.surround().normalized()] {
+            assert_relative_eq!(s.center(), point(0.0, 0.0, 3.0), epsilon = 1e-12);
+            assert_relative_eq!(s.radius(), 2.0, epsilon = 1e-12);
+        }
+
+        assert_relative_eq!(pair.carrier(), pair.extend());
+        assert_relative_eq!(circle.carrier(), circle.extend());
+        assert_relative_eq!(sphere.carrier().0.abs(), sphere.weight(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_tangent_at() {
+        let p = point(2.0, 0.0, 3.0);
+        let circle = p.outer(point(0.0, 2.0, 3.0)).outer(point(-2.0, 0.0, 3.0));
+        let sphere = circle.outer(point(0.0, 0.0, 5.0));
+
+        let pair = p.outer(point(2.0, 0.0, 7.0));
+        assert_relative_eq!(pair.tangent_at(p).normalized(), p, epsilon = 1e-12);
+        // Half the squared distance of 4 between the points.
+        assert_relative_eq!(pair.tangent_at(p).weight(), 8.0, epsilon = 1e-12);
+
+        let tangent = circle.tangent_at(p);
+        assert_relative_eq!(tangent.location(), p, epsilon = 1e-12);
+        assert_relative_eq!(tangent.size(), 0.0, epsilon = 1e-12);
+        let attitude = tangent.attitude();
+        assert_relative_eq!(attitude.e1, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(attitude.e3, 0.0, epsilon = 1e-12);
+
+        let tangent = sphere.tangent_at(p);
+        assert_relative_eq!(tangent.location(), p, epsilon = 1e-12);
+        assert_relative_eq!(tangent.size(), 0.0, epsilon = 1e-12);
+        let attitude = tangent.attitude();
+        assert_relative_eq!(attitude.e12, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(attitude.e13, 0.0, epsilon = 1e-12);
+    }
+}
@@ -1,14 +1,23 @@
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 
 use num_traits::zero;
 use simba::scalar::RealField;
 
-use super::super::dual::DPlane;
-use super::super::flat::Line;
+use super::super::direction::DVector;
+use super::super::dual::{DPlane, DSphere};
+use super::super::flat::{FPoint, Line};
+use super::super::free::Vector;
 use super::{Circle, Point};
-use crate::{Field, Inner, Multivec, Outer, Scalar, R410, Space, Euclidean};
+use crate::params::{null_parts, round_center};
+use crate::spaces::IBasis;
+use crate::{
+    Error, Euclidean, Field, Inner, Multivec, Normalize, ObjectParams, Outer, Scalar, Space, R410,
+};
 
+/// Laid out in memory as `[e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn]`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Pair<T, S = Euclidean> {
     pub(crate) e12: T,
     pub(crate) e13: T,
@@ -20,13 +29,119 @@ pub struct Pair<T, S = Euclidean> {
     pub(crate) e3p: T,
     pub(crate) e3n: T,
     pub(crate) epn: T,
-    pub(super) _pd: PhantomData<S>,
+    pub(crate) _pd: PhantomData<S>,
+}
+
+impl_traits!(Pair<S>{e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn, [_pd: PhantomData]});
+
+/// The possible shapes of a point pair, as found by [`Pair::decomposition`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PairDecomposition<T, S = Euclidean> {
+    /// Two distinct points, in the order of the outer product that made the pair.
+    Two(Point<T, S>, Point<T, S>),
+    /// A pair of zero size, which is a single point with a direction.
+    Tangent(Point<T, S>, DVector<T, S>),
+    /// An imaginary pair, which has a center and an imaginary radius.
+    Imaginary(Point<T, S>, T),
+    /// A point joined with the point at infinity, which is a flat point.
+    WithInfinity(FPoint<T, S>),
+    /// The zero pair, such as the outer product of a point with itself, which holds no points.
+    Zero,
+}
+
+/// Applies the linear map `m` to the `ep` and `en` components of the vector and bivector parts
+/// of `x`, leaving `e1`, `e2` and `e3` alone.
+#[inline]
+fn map_null_plane<T: RealField + Copy>(x: R410<T>, m: [[T; 2]; 2]) -> R410<T> {
+    let map = |p: T, n: T| (m[0][0] * p + m[0][1] * n, m[1][0] * p + m[1][1] * n);
+    let (ep, en) = map(x.ep, x.en);
+    let (e1p, e1n) = map(x.e1p, x.e1n);
+    let (e2p, e2n) = map(x.e2p, x.e2n);
+    let (e3p, e3n) = map(x.e3p, x.e3n);
+    let epn = x.epn * (m[0][0] * m[1][1] - m[0][1] * m[1][0]);
+    R410 { ep, en, e1p, e1n, e2p, e2n, e3p, e3n, epn, ..x }
+}
+
+/// The maps between the `ep`, `en` coordinates of `S` and those of Euclidean space that send
+/// `S::infinity()` to the Euclidean infinity and keep the origin in place.
+///
+/// Every space embeds `Point::new(x)` as `x + x²/2 ni + no` with its own `ni`, so these carry the
+/// points of `S` to the Euclidean points with the same coordinates and back again.
+#[inline]
+fn euclidean_maps<T: RealField + Copy, S: Space>() -> ([[T; 2]; 2], [[T; 2]; 2]) {
+    let IBasis { ep: a, en: b } = S::split(T::one());
+    let (one, half) = (T::one(), T::from_subset(&0.5));
+    let sum = a + b;
+    let to = [[(one + b) / sum, (one - a) / sum], [(one - b) / sum, (one + a) / sum]];
+    let from = [[(one + a) * half, (a - one) * half], [(b - one) * half, (one + b) * half]];
+    (to, from)
 }
 
 impl<T: RealField + Copy, S: Space> Pair<T, S> {
+    /// Splits the pair into its points, or finds its center when it has no distinct points.
+    ///
+    /// The split is done in the coordinates of [`Point::new`], so the points are found exactly
+    /// in every space. The pair contains infinity when its squared direction is at most `tol`
+    /// times the square root of its square. That ratio is twice the product of the weights of
+    /// its points, so pairs of normalized points are compared the same way wherever they are and
+    /// however far apart. The pair is tangent when its squared half-length, its `size` in
+    /// [`ObjectParams`], is at most `tol` in magnitude, whatever its position and weight. Both
+    /// are found from the Euclidean parts of the pair in the `no`, `ni` basis, which don't
+    /// cancel far from the origin.
+    ///
+    /// Points much closer to each other than to the origin can still lose their distance to
+    /// round-off when the pair is built, so such pairs are best built near the origin.
+    pub fn decomposition(self, tol: T) -> PairDecomposition<T, S> {
+        let pair = self.into_mv();
+        if pair.into_array().iter().all(|x| x.is_zero()) {
+            return PairDecomposition::Zero;
+        }
+
+        let (to, from) = euclidean_maps::<T, S>();
+        let ni = Euclidean::infinity();
+        let e = map_null_plane(pair, to);
+        let plane = ni | e;
+
+        // The squared direction `d²` between points `p` and `q` is `2 |p·q| = 2 sqrt|X²|` times
+        // their weights wherever they are, and vanishes when one of them is at infinity, leaving
+        // the flat point in the `no∧ni` and `ei∧ni` parts.
+        let direction = plane.e1 * plane.e1 + plane.e2 * plane.e2 + plane.e3 * plane.e3;
+        let [a, b, c, d] = null_parts(e);
+        let square = (a * a).s + (b * c).s * T::from_subset(&2.0) + d.s * d.s;
+        if direction <= tol * square.abs().sqrt() {
+            return PairDecomposition::WithInfinity(FPoint::from_mv(pair));
+        }
+
+        // The squared half-distance between the points, read from the same parts as
+        // `ObjectParams::size` so it holds far from the origin.
+        let (center, lambda) = round_center(e);
+        let size = lambda * T::from_subset(&2.0);
+        let back = |p: Vector<T>| {
+            let p = Point::<T>::new(p).into_mv();
+            Point::from_mv(map_null_plane(p, from))
+        };
+        if size.abs() <= tol {
+            let direction = map_null_plane(-plane ^ ni, from);
+            PairDecomposition::Tangent(back(center), DVector::from_mv(direction))
+        } else if size > T::zero() {
+            let offset = Vector::new(plane.e1, plane.e2, plane.e3) * (size / direction).sqrt();
+            PairDecomposition::Two(back(center + offset), back(center - offset))
+        } else {
+            PairDecomposition::Imaginary(back(center), (-size).sqrt())
+        }
+    }
+
     #[inline]
     pub fn decompose(self) -> Option<(Point<T, S>, Point<T, S>)> {
-        let s = self.inner(self).0.try_sqrt()?;
+        self.try_decompose().ok()
+    }
+
+    /// Splits the pair into its two points, failing with `Error::Imaginary` for an imaginary pair
+    /// and `Error::PointAtInfinity` if one of the points is infinitely far away.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn try_decompose(self) -> Result<(Point<T, S>, Point<T, S>), Error> {
+        let s = self.inner(self).0.try_sqrt().ok_or(Error::Imaginary)?;
 
         let pair = self.into_mv();
         let plane = S::infinity() | pair;
@@ -34,15 +149,91 @@ impl<T: RealField + Copy, S: Space> Pair<T, S> {
         if plane.e1.is_zero() && plane.e2.is_zero() && plane.e3.is_zero() {
             // Second point is infinity
             //Some((Point::from_mv(Point::no().into_mv() | pair).normalize(), Point::ni()))
-            None
+            Err(Error::PointAtInfinity)
         } else {
-            Some((
-                Point::from_mv((-pair + s) | plane).normalize(),
-                Point::from_mv((pair + s) | plane).normalize(),
+            Ok((
+                Point::from_mv((-pair + s) | plane).normalized(),
+                Point::from_mv((pair + s) | plane).normalized(),
             ))
         }
     }
+}
+
+/// Splits the pair into its two points with [`Pair::decomposition`], in the order of the outer
+/// product that made it. Fails with `Error::Imaginary` for an imaginary pair,
+/// `Error::PointAtInfinity` for a flat point and `Error::Degenerate` for a tangent or zero pair.
+/// No tolerance is applied, so use [`Pair::decomposition`] for pairs that are only nearly one of
+/// those.
+impl<T: RealField + Copy, S: Space> TryFrom<Pair<T, S>> for (Point<T, S>, Point<T, S>) {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(pair: Pair<T, S>) -> Result<Self, Error> {
+        match pair.decomposition(T::zero()) {
+            PairDecomposition::Two(a, b) => Ok((a, b)),
+            PairDecomposition::Imaginary(..) => Err(Error::Imaginary),
+            PairDecomposition::WithInfinity(_) => Err(Error::PointAtInfinity),
+            PairDecomposition::Tangent(..) | PairDecomposition::Zero => Err(Error::Degenerate),
+        }
+    }
+}
+
+/// Treats a real pair as the segment from its first point to its second.
+///
+/// These need a real pair: an imaginary pair has no length, so `length` and `lerp` give NaN for
+/// it, as they do for the zero pair, and `contains` is false. A tangent pair is a segment of
+/// length zero. Check the pair with [`Pair::decomposition`] first when it may not be real.
+///
+/// The length and midpoint come from [`ObjectParams`], which reads them from the parts of the
+/// pair in the `no`, `ni` basis, so they hold far from the origin as long as building the pair
+/// didn't already lose them to round-off.
+///
+/// The points themselves come from converting the pair into a tuple with [`TryFrom`].
+impl<T: RealField + Copy> Pair<T> {
+    /// The distance between the two points, or NaN for an imaginary pair.
+    #[inline]
+    pub fn length(self) -> T {
+        self.size().sqrt() * T::from_subset(&2.0)
+    }
+
+    /// The point halfway between the two points.
+    #[inline]
+    pub fn midpoint(self) -> Point<T> {
+        self.location()
+    }
+
+    /// The vector from the first point to the second, scaled by the weights of both points.
+    #[inline]
+    pub fn direction(self) -> Vector<T> {
+        let plane = Euclidean::infinity() | self.into_mv();
+        Vector::new(-plane.e1, -plane.e2, -plane.e3)
+    }
+
+    /// The point a fraction `t` of the way from the first point to the second. Not finite for
+    /// an imaginary pair.
+    #[inline]
+    pub fn lerp(self, t: T) -> Point<T> {
+        let half = T::from_subset(&0.5);
+        let offset = self.direction().normalize() * ((t - half) * self.length());
+        Point::new(self.midpoint().position() + offset)
+    }
+
+    /// Whether `p` lies within a distance `tol` of the segment. Always false for an imaginary
+    /// pair.
+    #[inline]
+    pub fn contains(self, p: Point<T>, tol: T) -> bool {
+        let half = match self.size().try_sqrt() {
+            Some(half) => half,
+            None => return false,
+        };
+        let direction = self.direction().normalize();
+        let v = p.position() - self.midpoint().position();
+        let along = v.dot(direction).max(-half).min(half);
+        (v - direction * along).norm() <= tol
+    }
+}
 
+impl<T: Field + Copy, S: Space> Pair<T, S> {
     #[inline]
     pub fn norm_squared(self) -> T {
         self.into_mv().norm_squared()
@@ -54,6 +245,31 @@ impl<T: RealField + Copy, S: Space> Pair<T, S> {
         Line::from_mv(self.into_mv() ^ S::infinity())
     }
 
+    /// The smallest flat containing the pair, which is the line through both points.
+    #[inline]
+    pub fn carrier(self) -> Line<T, S> {
+        self.extend()
+    }
+
+    /// Constructs the dual form of the sphere with the same center and radius as the pair.
+    #[inline]
+    pub fn surround(self) -> DSphere<T, S> {
+        super::surround(self.into_mv())
+    }
+
+    /// The tangent of the pair at one of its points, which is that point weighted by half the
+    /// squared distance to the other point, since `p⌋(p∧q) = -(p·q) p = d²/2 p`.
+    #[inline]
+    pub fn tangent_at(self, p: Point<T, S>) -> Point<T, S> {
+        Point::from_mv(super::tangent_at(self.into_mv(), p))
+    }
+
+    /// The same pair with its points swapped.
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Self::from_mv(-self.into_mv())
+    }
+
     /// Constructs the dual form of the plane halfway between the two points.
     #[inline]
     pub fn midplane(self) -> DPlane<T, S> {
@@ -127,7 +343,7 @@ impl<T: Field + Copy, S: Space> Multivec for Pair<T, S> {
 
 /// Construct the circle passing through all 3 points.
 impl<T: Field + Copy, S: Space> Outer<Point<T, S>> for Pair<T, S> {
-    type Output = Circle<T>;
+    type Output = Circle<T, S>;
 }
 
 impl<T: Field + Copy, S: Space> Inner for Pair<T, S> {
@@ -145,9 +361,9 @@ mod test {
 
     #[test]
     fn test_decompose() {
-        let p1 : Point<_> = Point::new([1.0, 0.0, 0.0]).normalize();
-        let p2 : Point<_> = Point::new([3.0, 4.0, 5.0]).normalize();
-        let p3 : Point<_> = Point::new([3.0, 4.0, 0.0]).normalize();
+        let p1 : Point<_> = Point::new([1.0, 0.0, 0.0]).normalized();
+        let p2 : Point<_> = Point::new([3.0, 4.0, 5.0]).normalized();
+        let p3 : Point<_> = Point::new([3.0, 4.0, 0.0]).normalized();
 
         assert_eq!(p1.outer(p2).decompose(), Some((p1, p2)));
         assert_eq!(p1.outer(p3).decompose(), Some((p1, p3)));
@@ -156,15 +372,6 @@ mod test {
         assert_eq!(p3.outer(p1).decompose(), Some((p3, p1)));
         assert_eq!(p3.outer(p2).decompose(), Some((p3, p2)));
 
-        /*
-        assert_eq!(p1.outer(Point::ni()).decompose(), Some((p1, Point::ni())));
-        assert_eq!(p2.outer(Point::ni()).decompose(), Some((p2, Point::ni())));
-        assert_eq!(p3.outer(Point::ni()).decompose(), Some((p3, Point::ni())));
-
-        assert_eq!(Point::ni().outer(p1).decompose(), Some((p1, Point::ni())));
-        assert_eq!(Point::ni().outer(p2).decompose(), Some((p2, Point::ni())));
-        assert_eq!(Point::ni().outer(p3).decompose(), Some((p3, Point::ni())));
-        */
         assert_eq!(p1.outer(Point::ni()).decompose(), None);
         assert_eq!(p2.outer(Point::ni()).decompose(), None);
         assert_eq!(p3.outer(Point::ni()).decompose(), None);
@@ -173,4 +380,285 @@ mod test {
         assert_eq!(Point::ni().outer(p2).decompose(), None);
         assert_eq!(Point::ni().outer(p3).decompose(), None);
     }
+
+    #[test]
+    fn test_decomposition() {
+        use approx::assert_relative_eq;
+
+        use super::super::super::free::Vector;
+        use crate::{Hyperbolic, Spherical};
+
+        let p1: Point<f64> = Point::new([1.0, 0.0, 0.0]);
+        let p2: Point<f64> = Point::new([3.0, 4.0, 5.0]);
+        match p1.outer(p2).decomposition(1e-9) {
+            PairDecomposition::Two(a, b) => {
+                assert_relative_eq!(a, p1, epsilon = 1e-12);
+                assert_relative_eq!(b, p2, epsilon = 1e-12);
+            }
+            other => panic!("expected two points, got {:?}", other),
+        }
+
+        assert_eq!(
+            p1.outer(Point::ni()).decomposition(1e-9),
+            PairDecomposition::WithInfinity(p1.into_flat())
+        );
+        assert_eq!(
+            Point::ni().outer(p1).decomposition(1e-9),
+            PairDecomposition::WithInfinity(FPoint::from_mv(-p1.into_flat().into_mv()))
+        );
+
+        // The tangent of a line at one of its points.
+        let line = p1.extend_along_vec(Vector::new(0.0, 1.0, 0.0).as_direction());
+        let tangent = Pair::from_mv(p1.into_mv() | line.into_mv());
+        match tangent.decomposition(1e-9) {
+            PairDecomposition::Tangent(p, dir) => {
+                assert_relative_eq!(p, p1, epsilon = 1e-12);
+                let dir = dir.into_mv();
+                assert_eq!((dir.e1p, dir.e3p), (0.0, 0.0));
+                assert!(dir.e2p != 0.0);
+            }
+            other => panic!("expected a tangent pair, got {:?}", other),
+        }
+
+        // Nearly coincident points count as a tangent when the tolerance allows it.
+        let q = Point::new([1.0, 0.0, 1e-3]);
+        assert!(matches!(p1.outer(q).decomposition(1e-9), PairDecomposition::Two(..)));
+        assert!(matches!(p1.outer(q).decomposition(1e-3), PairDecomposition::Tangent(..)));
+        assert_eq!(p1.outer(p1).decomposition(1e-9), PairDecomposition::Zero);
+
+        let circle = Point::<f64>::new([2.0, 0.0, 3.0])
+            .outer(Point::new([0.0, 2.0, 3.0]))
+            .outer(Point::new([-2.0, 0.0, 3.0]));
+        let imaginary = Pair::<f64>::from_mv(circle.into_mv().dual());
+        match imaginary.decomposition(1e-9) {
+            PairDecomposition::Imaginary(center, radius) => {
+                assert_relative_eq!(center, Point::new([0.0, 0.0, 3.0]), epsilon = 1e-12);
+                assert_relative_eq!(radius, 2.0, epsilon = 1e-12);
+            }
+            other => panic!("expected an imaginary pair, got {:?}", other),
+        }
+
+        decomposition_in::<Euclidean>();
+        decomposition_in::<Hyperbolic>();
+        decomposition_in::<Spherical>();
+    }
+
+    /// Checks every shape of pair in the space `S`, building pairs from their center `c`,
+    /// direction `d` and squared radius `r²` as `C∧D - r²/2 D∧ni`, where `D = d + (c·d) ni`.
+    fn decomposition_in<S: Space + core::fmt::Debug>() {
+        use approx::assert_relative_eq;
+
+        use super::super::super::free::Vector;
+
+        let p: Point<f64, S> = Point::new([0.1, 0.2, 0.0]);
+        let q: Point<f64, S> = Point::new([0.3, -0.1, 0.2]);
+        match p.outer(q).decomposition(1e-9) {
+            PairDecomposition::Two(a, b) => {
+                assert_relative_eq!(a, p, epsilon = 1e-12);
+                assert_relative_eq!(b, q, epsilon = 1e-12);
+            }
+            other => panic!("expected two points in {}, got {:?}", S::NAME, other),
+        }
+        assert_eq!(
+            p.outer(Point::ni()).decomposition(1e-9),
+            PairDecomposition::WithInfinity(p.into_flat())
+        );
+        assert_eq!(p.outer(p).decomposition(1e-9), PairDecomposition::Zero);
+
+        let c = Vector::new(0.2, 0.0, -0.1);
+        let d = Vector::new(0.0, 0.6, 0.8);
+        let ni = S::infinity::<f64>();
+        let big_d = d.into_mv() + ni * c.dot(d);
+        let round = |r2: f64| {
+            let pair = Point::<f64, S>::new(c).into_mv() ^ big_d;
+            Pair::<f64, S>::from_mv(pair - (big_d ^ ni) * (r2 * 0.5))
+        };
+        match round(0.0).decomposition(1e-9) {
+            PairDecomposition::Tangent(center, dir) => {
+                assert_relative_eq!(center, Point::new(c), epsilon = 1e-12);
+                let expected = DVector::from_mv(d.into_mv() ^ ni);
+                assert!(crate::eq_projective(dir, expected, 1e-12), "{:?}", dir);
+            }
+            other => panic!("expected a tangent pair in {}, got {:?}", S::NAME, other),
+        }
+        match round(-0.25).decomposition(1e-9) {
+            PairDecomposition::Imaginary(center, radius) => {
+                assert_relative_eq!(center, Point::new(c), epsilon = 1e-12);
+                assert_relative_eq!(radius, 0.5, epsilon = 1e-12);
+            }
+            other => panic!("expected an imaginary pair in {}, got {:?}", S::NAME, other),
+        }
+        // A real pair built the same way has the points `c ± r d`.
+        match round(0.25).decomposition(1e-9) {
+            PairDecomposition::Two(a, b) => {
+                assert_relative_eq!(a, Point::new(c - d * 0.5), epsilon = 1e-12);
+                assert_relative_eq!(b, Point::new(c + d * 0.5), epsilon = 1e-12);
+            }
+            other => panic!("expected two points in {}, got {:?}", S::NAME, other),
+        }
+    }
+
+    #[test]
+    fn test_decomposition_scale() {
+        use approx::assert_relative_eq;
+
+        use super::super::super::free::Vector;
+
+        let p: Point<f64> = Point::new([1.0, 0.0, 0.0]);
+        let q: Point<f64> = Point::new([3.0, 4.0, 5.0]);
+        for weight in [1e-6, 1e6] {
+            let scaled = |x: Pair<f64>| Pair::<f64>::from_mv(x.into_mv() * weight);
+            assert!(matches!(scaled(p.outer(q)).decomposition(1e-9), PairDecomposition::Two(..)));
+            assert!(matches!(
+                scaled(p.outer(Point::ni())).decomposition(1e-9),
+                PairDecomposition::WithInfinity(..)
+            ));
+        }
+
+        // Far from the origin, the squared radius of nearby points is lost in round-off when the
+        // pair is built, which a tolerance above that round-off absorbs instead of reporting an
+        // imaginary pair.
+        let a: Point<f64> = Point::new([1000.0, 0.0, 0.0]);
+        for offset in [Vector::new(0.0, 1e-4, 0.0), Vector::new(0.0, 0.0, -1e-4)] {
+            let b = Point::new(a.position() + offset);
+            match a.outer(b).decomposition(1e-4) {
+                PairDecomposition::Tangent(center, _) => {
+                    let expected = a.position() + offset * 0.5;
+                    assert_relative_eq!(center.position(), expected, epsilon = 1e-4);
+                }
+                other => panic!("expected a tangent pair, got {:?}", other),
+            }
+        }
+        // A unit segment splits the same way at any distance from the origin.
+        for x in [0.0, 1000.0, -1000.0] {
+            let p: Point<f64> = Point::new([x, 0.0, 0.0]);
+            let q: Point<f64> = Point::new([x + 1.0, 0.0, 0.0]);
+            match p.outer(q).decomposition(1e-3) {
+                PairDecomposition::Two(a, b) => {
+                    assert_relative_eq!(a.position(), p.position(), epsilon = 1e-3);
+                    assert_relative_eq!(b.position(), q.position(), epsilon = 1e-3);
+                }
+                other => panic!("expected two points at {}, got {:?}", x, other),
+            }
+            let flat = p.outer(Point::ni()).decomposition(1e-3);
+            assert_eq!(flat, PairDecomposition::WithInfinity(p.into_flat()));
+        }
+
+        let b: Point<f64> = Point::new([1000.0, 1.0, 0.0]);
+        match a.outer(b).decomposition(1e-9) {
+            PairDecomposition::Two(x, y) => {
+                assert_relative_eq!(x, a, max_relative = 1e-9);
+                assert_relative_eq!(y, b, max_relative = 1e-9);
+            }
+            other => panic!("expected two points, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        use core::convert::TryInto;
+
+        use approx::assert_relative_eq;
+
+        let a: Point<f64> = Point::new([1.0, 2.0, 0.0]);
+        let b: Point<f64> = Point::new([1.0, 5.0, 4.0]);
+        let (x, y): (Point<f64>, Point<f64>) = a.outer(b).try_into().unwrap();
+        assert_relative_eq!(x, a, epsilon = 1e-12);
+        assert_relative_eq!(y, b, epsilon = 1e-12);
+        let (x, y): (Point<f64>, Point<f64>) = b.outer(a).try_into().unwrap();
+        assert_relative_eq!(x, b, epsilon = 1e-12);
+        assert_relative_eq!(y, a, epsilon = 1e-12);
+
+        let far: Point<f64> = Point::new([1e5, 0.0, 0.0]);
+        let near: Point<f64> = Point::new([1e5, 1.0, 0.0]);
+        let (x, y): (Point<f64>, Point<f64>) = far.outer(near).try_into().unwrap();
+        assert_relative_eq!(x.position(), far.position(), epsilon = 1e-6);
+        assert_relative_eq!(y.position(), near.position(), epsilon = 1e-6);
+
+        let split = |pair: Pair<f64>| <(Point<f64>, Point<f64>)>::try_from(pair);
+        assert_eq!(split(a.outer(Point::ni())), Err(Error::PointAtInfinity));
+        assert_eq!(split(a.outer(a)), Err(Error::Degenerate));
+        let circle = a.outer(b).outer(Point::new([3.0, 2.0, 0.0]));
+        assert_eq!(split(Pair::from_mv(circle.into_mv().dual())), Err(Error::Imaginary));
+    }
+
+    #[test]
+    fn test_segment() {
+        use approx::assert_relative_eq;
+
+        let a: Point<f64> = Point::new([1.0, 2.0, 0.0]);
+        let b: Point<f64> = Point::new([1.0, 5.0, 4.0]);
+        let segment = a.outer(b);
+        assert_relative_eq!(segment.length(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(segment.midpoint(), Point::new([1.0, 3.5, 2.0]), epsilon = 1e-12);
+        assert_relative_eq!(segment.direction(), Vector::new(0.0, 3.0, 4.0), epsilon = 1e-12);
+        assert_relative_eq!(segment.lerp(0.0), a, epsilon = 1e-12);
+        assert_relative_eq!(segment.lerp(1.0), b, epsilon = 1e-12);
+        assert_relative_eq!(segment.lerp(0.2), Point::new([1.0, 2.6, 0.8]), epsilon = 1e-12);
+
+        let reversed = segment.reverse();
+        assert_eq!(reversed, b.outer(a));
+        assert_relative_eq!(reversed.lerp(0.0), b, epsilon = 1e-12);
+        assert_relative_eq!(reversed.length(), 5.0, epsilon = 1e-12);
+
+        // The length does not depend on the weights of the points.
+        let weighted = Pair::<f64>::from_mv(segment.into_mv() * -3.0);
+        assert_relative_eq!(weighted.length(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(weighted.lerp(0.0), b, epsilon = 1e-12);
+
+        assert!(segment.contains(a, 1e-9));
+        assert!(segment.contains(Point::new([1.0, 3.5, 2.0]), 1e-9));
+        assert!(segment.contains(Point::new([1.0, 3.5, 2.1]), 0.1));
+        assert!(!segment.contains(Point::new([1.0, 3.5, 2.1]), 0.05));
+        assert!(!segment.contains(Point::new([1.0, 8.0, 8.0]), 1e-9));
+
+        // A tangent pair is a segment of length zero, while an imaginary pair has no length.
+        let line = a.extend_along_vec(Vector::new(0.0, 1.0, 0.0).as_direction());
+        let tangent = Pair::<f64>::from_mv(a.into_mv() | line.into_mv());
+        assert_relative_eq!(tangent.length(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(tangent.lerp(0.7), a, epsilon = 1e-12);
+        assert!(tangent.contains(a, 1e-9));
+        let circle = a.outer(b).outer(Point::new([3.0, 2.0, 0.0]));
+        let imaginary = Pair::<f64>::from_mv(circle.into_mv().dual());
+        assert!(imaginary.length().is_nan());
+        assert!(!imaginary.contains(imaginary.midpoint(), 1.0));
+    }
+
+    #[test]
+    fn test_far_segment() {
+        use approx::assert_relative_eq;
+
+        // Segments away from the origin, across and along the direction to the origin.
+        for x in [100.0f32, -100.0] {
+            for (a, b, tol) in [
+                ([x, 0.0, 0.0], [x, 1.0, 0.0], 1e-3),
+                ([x, 3.0, 0.0], [x + 1.0, 3.0, 0.0], 2e-2),
+            ] {
+                let (a, b) = (Point::<f32>::new(a), Point::<f32>::new(b));
+                let segment = a.outer(b);
+                assert_relative_eq!(segment.length(), 1.0, epsilon = tol);
+                let middle = (a.position() + b.position()) * 0.5;
+                assert_relative_eq!(segment.midpoint().position(), middle, epsilon = tol);
+                assert_relative_eq!(segment.lerp(1.0).position(), b.position(), epsilon = tol);
+                assert!(segment.contains(a, tol) && segment.contains(b, tol));
+                assert!(!segment.contains(Point::new(middle * 1.1), tol));
+            }
+        }
+
+        for (x, direction, tol) in [
+            (1e5, [0.0, 1.0, 0.0], 1e-6),
+            (1e4, [1.0, 0.0, 0.0], 1e-6),
+            (-1e3, [0.6, 0.8, 0.0], 1e-3),
+        ] {
+            let a: Point<f64> = Point::new([x, 2.0, 0.0]);
+            let b: Point<f64> = Point::new(a.position() + Vector::from(direction));
+            let segment = a.outer(b);
+            assert_relative_eq!(segment.length(), 1.0, epsilon = tol);
+            let middle = (a.position() + b.position()) * 0.5;
+            assert_relative_eq!(segment.midpoint().position(), middle, epsilon = tol);
+            assert_relative_eq!(segment.lerp(0.0).position(), a.position(), epsilon = tol);
+            assert!(segment.contains(a, tol) && segment.contains(b, tol));
+            assert!(!segment.contains(Point::new(middle * 1.1), tol));
+        }
+    }
 }
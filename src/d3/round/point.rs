@@ -1,12 +1,9 @@
-use core::fmt::{self, Display, Formatter, LowerExp, UpperExp};
 use core::marker::PhantomData;
 
-use simba::scalar::{RealField, SubsetOf, SupersetOf};
-use simba::simd::SimdValue;
+use simba::scalar::RealField;
 
 use num_traits::{zero};
 
-use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use super::super::direction::DVector;
 use super::super::dual::{DPlane, DSphere};
@@ -14,9 +11,11 @@ use super::super::flat::{FPoint, Line};
 use super::super::free::Vector;
 
 use super::{Pair, Circle, Sphere};
-use crate::{Euclidean, Field, Inner, Multivec, Outer, Scalar, Space, R410};
+use crate::{AntiCommutator, Commutator, Euclidean, Field, Error, Inner, Multivec, Normalize, Outer, Scalar, Space, R410};
 
+/// Laid out in memory as `[e1, e2, e3, ep, en]`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
 pub struct Point<T, S = Euclidean> {
     pub(crate) e1: T,
     pub(crate) e2: T,
@@ -26,7 +25,7 @@ pub struct Point<T, S = Euclidean> {
     pub(crate) _pd: PhantomData<S>,
 }
 
-impl_traits!(Point{e1, e2, e3, ep, en, [_pd: PhantomData]});
+impl_traits!(Point<S>{e1, e2, e3, ep, en, [_pd: PhantomData]});
 
 impl<T: Field + Copy, S: Space> From<[T; 3]> for Point<T, S> {
     fn from(v: [T; 3]) -> Self {
@@ -76,11 +75,21 @@ impl<T: Field + Copy, S: Space> Point<T, S> {
         FPoint::from_mv(self.into_mv() ^ S::infinity())
     }
 
+    /// The coordinates of the point, independent of its weight.
+    #[inline]
+    pub fn position(self) -> Vector<T> {
+        // Self = w (x + x²/2 ni + no), so the weight is the coefficient of no.
+        let ni = S::split(T::one());
+        let two = T::from_subset(&2.0);
+        let w = two * (ni.ep * self.en - ni.en * self.ep) / (ni.ep + ni.en);
+        Vector::new(self.e1 / w, self.e2 / w, self.e3 / w)
+    }
+
     /// Calculate the euclidean distance between two points
     #[inline]
     pub fn distance(self, other: Self) -> T {
         let n2 = T::from_subset(&-2.0);
-        (self.normalize().dot(other.normalize()) * n2).simd_sqrt()
+        (self.normalized().dot(other.normalized()) * n2).simd_sqrt()
     }
 
     /// The scalar product of two points. Returns negative a half of the distance squared between them.
@@ -89,27 +98,15 @@ impl<T: Field + Copy, S: Space> Point<T, S> {
         self.inner(other).0
     }
 
-    #[inline]
-    pub(crate) fn normalize(self) -> Self {
-        let norm = self.norm();
-        assert!(!norm.is_zero());
-        Self::from_mv(self.into_mv() / norm)
-    }
-
-    #[inline]
-    fn norm(self) -> T {
-        self.dot(Self::ni())
-    }
-
     /// Constructs the dual form of the plane halfway between the two points.
     pub fn midplane(self, other: Self) -> DPlane<T, S> {
         DPlane::from_mv(self.into_mv() - other.into_mv())
     }
 
     /// Constructs the dual form of the sphere centered at this point with the given radius.
-    pub fn into_sphere(self, radius: T) -> DSphere<T> {
+    pub fn into_sphere(self, radius: T) -> DSphere<T, S> {
         let half = T::from_subset(&0.5);
-        let r2 = radius.clone() * radius * half;
+        let r2 = radius * radius * half;
         DSphere::from_mv(self.into_mv() - S::infinity() * r2)
     }
 
@@ -159,11 +156,21 @@ impl<T: Field + Copy, S: Space> Multivec for Point<T, S> {
 }
 
 impl<T: RealField + Copy, S: Space> Point<T, S> {
+    /// The coordinates of the point, or `Error::PointAtInfinity` if it has no weight.
+    #[inline]
+    pub fn try_position(self) -> Result<Vector<T>, Error> {
+        if self.weight().is_zero() {
+            Err(Error::PointAtInfinity)
+        } else {
+            Ok(self.position())
+        }
+    }
+
     /// gets the vector pointing to this point from the origin
     #[inline]
     pub fn from_origin(self) -> Option<Vector<T>> {
         let pair = Self::no().outer(self);
-        if self.norm().is_zero() {
+        if self.weight().is_zero() {
             None
         } else {
             // (x + x^2(ep + en)/2 + (en/2 - ep/2)) | (en/2 - ep/2)
@@ -180,24 +187,6 @@ impl<T: RealField + Copy, S: Space> Point<T, S> {
     }
 }
 
-impl<T: Field + Copy + Display, S: Space> Display for Point<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Point({})", self.into_mv())
-    }
-}
-
-impl<T: Field + Copy + LowerExp, S: Space> LowerExp for Point<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Point({:e})", self.into_mv())
-    }
-}
-
-impl<T: Field + Copy + UpperExp, S: Space> UpperExp for Point<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Point({:E})", self.into_mv())
-    }
-}
-
 impl<T: Field + Copy, S: Space> Inner for Point<T, S> {
     type Output = Scalar<T>;
 }
@@ -211,6 +200,16 @@ impl<T: Field + Copy, S: Space> Outer for Point<T, S> {
     type Output = Pair<T, S>;
 }
 
+/// Equivalent to the outer product for points.
+impl<T: Field + Copy, S: Space> Commutator for Point<T, S> {
+    type Output = Pair<T, S>;
+}
+
+/// Equivalent to the inner product for points.
+impl<T: Field + Copy, S: Space> AntiCommutator for Point<T, S> {
+    type Output = Scalar<T>;
+}
+
 impl<T: Field + Copy, S: Space> Outer<FPoint<T, S>> for Point<T, S>
 where
     FPoint<T, S>: Multivec<Element = T>,
@@ -222,60 +221,6 @@ impl<T: Field + Copy, S: Space> Outer<DVector<T, S>> for Point<T, S> {
     type Output = Line<T, S>;
 }
 
-impl<T: AbsDiffEq, S: Space> AbsDiffEq for Point<T, S>
-where
-    T::Epsilon: Copy,
-{
-    type Epsilon = T::Epsilon;
-    fn default_epsilon() -> Self::Epsilon {
-        T::default_epsilon()
-    }
-    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        T::abs_diff_eq(&self.e1, &other.e1, epsilon)
-            && T::abs_diff_eq(&self.e2, &other.e2, epsilon)
-            && T::abs_diff_eq(&self.e3, &other.e3, epsilon)
-            && T::abs_diff_eq(&self.ep, &other.ep, epsilon)
-            && T::abs_diff_eq(&self.en, &other.en, epsilon)
-    }
-}
-
-impl<T: RelativeEq, S: Space> RelativeEq for Point<T, S>
-where
-    T::Epsilon: Copy,
-{
-    fn default_max_relative() -> Self::Epsilon {
-        T::default_max_relative()
-    }
-    fn relative_eq(
-        &self,
-        other: &Self,
-        epsilon: Self::Epsilon,
-        max_relative: Self::Epsilon,
-    ) -> bool {
-        T::relative_eq(&self.e1, &other.e1, epsilon, max_relative)
-            && T::relative_eq(&self.e2, &other.e2, epsilon, max_relative)
-            && T::relative_eq(&self.e3, &other.e3, epsilon, max_relative)
-            && T::relative_eq(&self.ep, &other.ep, epsilon, max_relative)
-            && T::relative_eq(&self.en, &other.en, epsilon, max_relative)
-    }
-}
-
-impl<T: UlpsEq, S: Space> UlpsEq for Point<T, S>
-where
-    T::Epsilon: Copy,
-{
-    fn default_max_ulps() -> u32 {
-        T::default_max_ulps()
-    }
-    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.e1, &other.e1, epsilon, max_ulps)
-            && T::ulps_eq(&self.e2, &other.e2, epsilon, max_ulps)
-            && T::ulps_eq(&self.e3, &other.e3, epsilon, max_ulps)
-            && T::ulps_eq(&self.ep, &other.ep, epsilon, max_ulps)
-            && T::ulps_eq(&self.en, &other.en, epsilon, max_ulps)
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -374,6 +319,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_position() {
+        use crate::{Hyperbolic, Spherical};
+        let v = Vector::new(1.0, -2.0, 4.0);
+        for p in [
+            v.into_point::<Euclidean>().position(),
+            Point::<_, Euclidean>::from_mv(v.into_point::<Euclidean>().into_mv() * -3.0).position(),
+            v.into_point::<Hyperbolic>().position(),
+            v.into_point::<Spherical>().position(),
+        ] {
+            assert_eq!(p, v);
+        }
+    }
+
     #[test]
     fn test_distance() {
         let v1 = Vector::new(1.0_f32, 2.0, 4.0);
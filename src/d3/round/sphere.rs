@@ -1,14 +1,15 @@
-use core::fmt::{self, Display, Formatter, LowerExp, UpperExp};
 use core::marker::PhantomData;
 
 use num_traits::zero;
 
 use super::super::dual::DSphere;
-use super::Point;
-use crate::{Dual, Field, Multivec, R410, Space, Euclidean};
+use super::{Circle, Point};
+use crate::{Dual, Field, Multivec, R410, Scalar, Space, Euclidean};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Sphere<T: Field, S = Euclidean> {
+/// Laid out in memory as `[e123p, e123n, e12pn, e13pn, e23pn]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Sphere<T, S = Euclidean> {
     pub(crate) e123p: T,
     pub(crate) e123n: T,
     pub(crate) e12pn: T,
@@ -17,6 +18,8 @@ pub struct Sphere<T: Field, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(Sphere<S>{e123p, e123n, e12pn, e13pn, e23pn, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> Multivec for Sphere<T, S> {
     type Element = T;
     #[inline]
@@ -61,30 +64,32 @@ impl<T: Field + Copy, S: Space> Multivec for Sphere<T, S> {
 }
 
 impl<T: Field + Copy, S: Space> Sphere<T, S> {
+    /// The center of the sphere, with a weight of one.
     #[inline]
     pub fn center(self) -> Point<T, S> {
         let ni = S::infinity();
         let s = self.into_mv();
         let p = s * ni * s;
-        Point::from_mv(p / (p | ni))
+        Point::from_mv(p / -(p | ni))
     }
-}
 
-impl<T: Field + Copy + Display, S: Space> Display for Sphere<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Sphere({})", self.into_mv())
+    /// The smallest flat containing the sphere is all of space, so this is the weight of the
+    /// pseudoscalar `S∧ni`.
+    #[inline]
+    pub fn carrier(self) -> Scalar<T> {
+        Scalar((self.into_mv() ^ S::infinity()).e123pn)
     }
-}
 
-impl<T: Field + Copy + LowerExp, S: Space> LowerExp for Sphere<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Sphere({:e})", self.into_mv())
+    /// The dual form of this sphere, which is its own surround.
+    #[inline]
+    pub fn surround(self) -> DSphere<T, S> {
+        super::surround(self.into_mv())
     }
-}
 
-impl<T: Field + Copy + UpperExp, S: Space> UpperExp for Sphere<T, S> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Sphere({:E})", self.into_mv())
+    /// The tangent plane of the sphere at a point on it, as a circle of zero size.
+    #[inline]
+    pub fn tangent_at(self, p: Point<T, S>) -> Circle<T, S> {
+        Circle::from_mv(super::tangent_at(self.into_mv(), p))
     }
 }
 
@@ -107,13 +112,14 @@ impl<T: Field + Copy, S: Space> Dual for Sphere<T, S> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Normalize;
     use approx::assert_abs_diff_eq;
 
     #[test]
     fn test_center() {
-        let p1 = Point::new([1.0, 0.0, 0.0]).normalize();
-        let p2 = Point::new([3.0, 4.0, 5.0]).normalize();
-        let p3 = Point::new([3.0, 4.0, 0.0]).normalize();
+        let p1 = Point::<_>::new([1.0, 0.0, 0.0]).normalized();
+        let p2 = Point::<_>::new([3.0, 4.0, 5.0]).normalized();
+        let p3 = Point::<_>::new([3.0, 4.0, 0.0]).normalized();
 
         assert_abs_diff_eq!(p1.into_sphere(1.0).undual().center(), p1);
         assert_abs_diff_eq!(p2.into_sphere(1.0).undual().center(), p2);
@@ -0,0 +1,115 @@
+use core::ops::Mul;
+
+use num_traits::zero;
+
+use simba::simd::SimdRealField as Field;
+
+use super::super::direction::DVector;
+use super::super::dual::{DLine, DPlane, DSphere};
+use super::super::flat::{FPoint, Line, Plane};
+use super::super::round::{Circle, Pair, Point, Sphere};
+use super::Transform;
+use crate::{Multivec, R410};
+
+/// A uniform scaling about the origin.
+///
+/// Laid out in memory as `[s, epn]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Dilator<T> {
+    pub(crate) s: T,
+    pub(crate) epn: T,
+}
+
+impl_traits!(Dilator{s, epn, []});
+
+impl<T: Field + Copy> Dilator<T> {
+    /// Constructs the dilator that scales every object about the origin by `scale`,
+    /// which must be positive.
+    #[inline]
+    pub fn new(scale: T) -> Self {
+        let half = T::from_subset(&0.5);
+        let root = scale.simd_sqrt();
+        let inv = root.simd_recip();
+        Self {
+            s: (root + inv) * half,
+            epn: (inv - root) * half,
+        }
+    }
+
+    /// The factor this dilator scales by.
+    #[inline]
+    pub fn scale(self) -> T {
+        (self.s - self.epn) / (self.s + self.epn)
+    }
+
+    /// The dilator that undoes this one.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Self::from_mv(self.into_mv().reverse() / self.into_mv().norm_squared())
+    }
+}
+
+impl<T: Field + Copy> Multivec for Dilator<T> {
+    type Element = T;
+    #[inline]
+    fn into_mv(self) -> R410<T> {
+        let Self { s, epn } = self;
+        R410 { s, epn, ..zero() }
+    }
+
+    #[inline]
+    fn from_mv(v: R410<T>) -> Self {
+        let R410 { s, epn, .. } = v;
+        Self { s, epn }
+    }
+}
+
+impl<T: Field + Copy> Transform<Point<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<Pair<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<Circle<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<Sphere<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<FPoint<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<Line<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<Plane<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<DLine<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<DPlane<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<DSphere<T>> for Dilator<T> {}
+impl<T: Field + Copy> Transform<DVector<T>> for Dilator<T> {}
+
+impl<T: Field + Copy> Mul for Dilator<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_mv(self.into_mv() * rhs.into_mv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{assert_transforms_flats, assert_transforms_rounds};
+    use super::*;
+    use crate::Euclidean;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_scale() {
+        let d = Dilator::new(2.5);
+        assert_relative_eq!(d.scale(), 2.5, epsilon = 1e-12);
+        assert_relative_eq!((d * Dilator::new(2.0)).scale(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(d.inverse().scale(), 0.4, epsilon = 1e-12);
+
+        let p = Point::<f64>::new([1.0, -2.0, 0.5]);
+        let q = Point::<f64>::new([0.0, 3.0, 1.0]);
+        let scaled = d.transform(p).distance(d.transform(q));
+        assert_relative_eq!(scaled, p.distance(q) * 2.5, epsilon = 1e-12);
+        assert_relative_eq!(d.transform(p).position().y(), -5.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_sandwich() {
+        let d = Dilator::new(0.3);
+        assert_transforms_rounds::<Euclidean, _>(d);
+        assert_transforms_flats::<Euclidean, _>(d);
+    }
+}
@@ -0,0 +1,49 @@
+use super::Motor;
+use crate::Field;
+
+/// A dual quaternion `real + ε dual`, in the layout commonly used for skinning.
+///
+/// Both quaternions are stored as `[w, x, y, z]`, matching [`Rotor::to_quaternion`]. A rigid
+/// transform that rotates by `r` and then translates by `t` has `real = r` and
+/// `dual = ½ t r`, where `t` is the pure quaternion `[0, tx, ty, tz]`.
+///
+/// Converting to and from [`Motor`] is exact: both have eight components and the mapping between
+/// them is a signed permutation.
+///
+/// [`Rotor::to_quaternion`]: super::Rotor::to_quaternion
+///
+/// Laid out in memory as `[real, dual]`, eight scalars in total.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct DualQuaternion<T> {
+    pub real: [T; 4],
+    pub dual: [T; 4],
+}
+
+impl<T: Field + Copy> From<Motor<T>> for DualQuaternion<T> {
+    #[inline]
+    fn from(m: Motor<T>) -> Self {
+        Self {
+            real: [m.s, -m.e23, m.e13, -m.e12],
+            dual: [-m.e123i, -m.e1i, -m.e2i, -m.e3i],
+        }
+    }
+}
+
+impl<T: Field + Copy> From<DualQuaternion<T>> for Motor<T> {
+    #[inline]
+    fn from(q: DualQuaternion<T>) -> Self {
+        let [w, x, y, z] = q.real;
+        let [dw, dx, dy, dz] = q.dual;
+        Motor {
+            s: w,
+            e12: -z,
+            e13: y,
+            e23: -x,
+            e1i: -dx,
+            e2i: -dy,
+            e3i: -dz,
+            e123i: -dw,
+        }
+    }
+}
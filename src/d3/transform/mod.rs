@@ -1,16 +1,152 @@
+mod dilator;
+mod dual_quaternion;
 mod motor;
 mod rotor;
 mod translate;
 
+pub use dilator::Dilator;
+pub use dual_quaternion::DualQuaternion;
 pub use motor::Motor;
-pub use rotor::Rotor;
+pub use rotor::{EulerOrder, Rotor};
 pub use translate::Translator;
 
-use crate::Multivec;
+use simba::scalar::RealField;
 
+use crate::{eq_projective, Multivec, Space};
+
+/// An object that acts on others through the sandwich product.
+///
+/// Every versor acts from the left: `v.transform(x)` computes `v̂ x ~v`, where `v̂` is the grade
+/// involution of `v`. For the even versors (`Rotor`, `Translator` and `Motor`) this is just
+/// `v x ~v`, while odd versors such as `DPlane` pick up the sign that makes them reflections.
+/// Composition follows the same order, so `(a * b).transform(x)` is `a.transform(b.transform(x))`.
 pub trait Transform<T: Multivec<Element = Self::Element>>: Multivec {
     #[inline]
     fn transform(self, obj: T) -> T {
-        T::from_mv(self.into_mv() >> obj.into_mv())
+        let v = self.into_mv();
+        T::from_mv(v.involute() * obj.into_mv() * v.reverse())
+    }
+}
+
+macro_rules! impl_eq_projective {
+    ($($t:ident $(<$s:ident>)?),*) => {$(
+        impl<T: RealField + Copy $(, $s: Space)?> $t<T $(, $s)?> {
+            /// Checks whether the two versors perform the same transformation, which makes them
+            /// equal up to a nonzero scale. In particular `v` and `-v` always compare equal.
+            ///
+            /// `epsilon` is the tolerance on each component after scaling both to unit length.
+            #[inline]
+            pub fn eq_projective(self, other: Self, epsilon: T) -> bool {
+                eq_projective(self, other, epsilon)
+            }
+        }
+    )*};
+}
+
+impl_eq_projective!(Rotor, Translator<S>, Motor, Dilator);
+
+#[cfg(test)]
+pub(crate) mod test {
+    use approx::assert_relative_eq;
+    use num_traits::zero;
+
+    use super::{Motor, Rotor, Transform, Translator};
+    use crate::d3::direction::DVector;
+    use crate::d3::dual::{DLine, DPlane, DSphere};
+    use crate::d3::flat::{FPoint, Line, Plane};
+    use crate::d3::free::Vector;
+    use crate::d3::round::{Circle, Pair, Point, Sphere};
+    use crate::{Hyperbolic, Multivec, Outer, Space};
+
+    /// Asserts that `m.transform(obj)` matches the R410 sandwich without dropping any components.
+    pub(crate) fn assert_sandwich<M, X>(m: M, obj: X)
+    where
+        M: Transform<X, Element = f64> + Copy,
+        X: Multivec<Element = f64> + Copy,
+    {
+        let expected = m.into_mv() >> obj.into_mv();
+        let actual = m.transform(obj).into_mv();
+        for (a, e) in actual.into_array().iter().zip(expected.into_array().iter()) {
+            assert_relative_eq!(a, e, epsilon = 1e-9);
+        }
+    }
+
+    fn points<S: Space>() -> [Point<f64, S>; 4] {
+        [
+            Point::new([1.0, 0.0, 0.0]),
+            Point::new([3.0, 4.0, 5.0]),
+            Point::new([-2.0, 1.0, 0.5]),
+            Point::new([0.0, -1.0, 2.0]),
+        ]
+    }
+
+    /// Checks `m` against the sandwich for every round and dual flat in the space `S`.
+    pub(crate) fn assert_transforms_rounds<S, M>(m: M)
+    where
+        S: Space,
+        M: Transform<Point<f64, S>, Element = f64>
+            + Transform<Pair<f64, S>>
+            + Transform<Circle<f64, S>>
+            + Transform<Sphere<f64, S>>
+            + Transform<DLine<f64, S>>
+            + Transform<DPlane<f64, S>>
+            + Transform<DSphere<f64, S>>
+            + Copy,
+    {
+        let [p1, p2, p3, p4] = points::<S>();
+        let d1: DPlane<f64, S> = Vector::new(1.0, 2.0, 3.0).normal(1.5);
+        let d2: DPlane<f64, S> = Vector::new(0.0, -1.0, 1.0).normal(-0.5);
+
+        assert_sandwich(m, p1);
+        assert_sandwich(m, p1.outer(p2));
+        assert_sandwich(m, p1.outer(p2).outer(p3));
+        assert_sandwich(m, p1.outer(p2).outer(p3).outer(p4));
+        assert_sandwich(m, DLine::<f64, S>::from_mv(d1.into_mv() ^ d2.into_mv()));
+        assert_sandwich(m, d1);
+        assert_sandwich(m, p2.into_sphere(2.0));
+    }
+
+    /// Checks `m` against the sandwich for every flat and direction in the space `S`.
+    pub(crate) fn assert_transforms_flats<S, M>(m: M)
+    where
+        S: Space,
+        M: Transform<FPoint<f64, S>, Element = f64>
+            + Transform<Line<f64, S>>
+            + Transform<Plane<f64, S>>
+            + Transform<DVector<f64, S>>
+            + Copy,
+    {
+        let [p1, p2, p3, _] = points::<S>();
+
+        assert_sandwich(m, p1.into_flat());
+        assert_sandwich(m, p1.outer(p2).extend());
+        assert_sandwich(m, p1.outer(p2).outer(p3).extend());
+        assert_sandwich(m, Vector::new(1.0, -2.0, 0.5).as_direction::<S>());
+    }
+
+    #[test]
+    fn test_eq_projective() {
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        let m = Motor::new(r, Vector::new(3.0, -1.0, 0.25));
+        let neg = |m: Motor<f64>| Motor::from_mv(-m.into_mv());
+
+        assert!(r.eq_projective(Rotor::from_mv(-r.into_mv()), 1e-12));
+        assert!(r.eq_projective(Rotor::from_mv(r.into_mv() * 3.0), 1e-12));
+        assert!(m.eq_projective(neg(m), 1e-12));
+        assert!(m.eq_projective(Motor::from_mv(m.into_mv() * -0.25), 1e-12));
+        assert_ne!(m, neg(m));
+
+        // A full turn negates the rotor without changing the rotation.
+        let tau = 2.0 * core::f64::consts::PI;
+        let turn = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8 + tau);
+        assert_relative_eq!(turn, Rotor::from_mv(-r.into_mv()), epsilon = 1e-12);
+        assert!(turn.eq_projective(r, 1e-12));
+
+        assert!(!r.eq_projective(Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.81), 1e-6));
+        assert!(!m.eq_projective(Motor::new(r, Vector::new(3.0, -1.0, 0.3)), 1e-6));
+        let t = Vector::<f64>::new(1.0, 2.0, 3.0).into_translator::<Hyperbolic>();
+        assert!(t.eq_projective(t * -2.0, 1e-12));
+        assert!(!t.eq_projective(Vector::new(1.0, 2.0, 3.1).into_translator(), 1e-6));
+        assert!(!t.eq_projective(Translator::from_mv(zero()), 1e-6));
     }
 }
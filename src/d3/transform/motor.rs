@@ -1,7 +1,22 @@
+use core::ops::Mul;
+
+use num_traits::zero;
+
+use simba::scalar::RealField;
 use simba::simd::SimdRealField as Field;
 
-#[derive(Copy, Clone, Debug)]
-pub struct Motor<T: Field> {
+use super::super::direction::DVector;
+use super::super::dual::{DLine, DPlane, DSphere};
+use super::super::flat::{FPoint, Line, Plane};
+use super::super::free::Vector;
+use super::super::round::{Circle, Pair, Point, Sphere};
+use super::{Rotor, Transform, Translator};
+use crate::{Error, Euclidean, Multivec, Space, R410};
+
+/// Laid out in memory as `[s, e12, e13, e23, e1i, e2i, e3i, e123i]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Motor<T> {
     pub(crate) s: T,
     pub(crate) e12: T,
     pub(crate) e13: T,
@@ -11,3 +26,424 @@ pub struct Motor<T: Field> {
     pub(crate) e3i: T,
     pub(crate) e123i: T,
 }
+
+impl_traits!(Motor{s, e12, e13, e23, e1i, e2i, e3i, e123i, []});
+
+impl<T: Field + Copy> Multivec for Motor<T> {
+    type Element = T;
+    #[inline]
+    fn into_mv(self) -> R410<T> {
+        let Motor {
+            s,
+            e12,
+            e13,
+            e23,
+            e1i,
+            e2i,
+            e3i,
+            e123i,
+        } = self;
+        R410 {
+            s,
+            e12,
+            e13,
+            e23,
+            e1p: Euclidean::split(e1i).ep,
+            e1n: Euclidean::split(e1i).en,
+            e2p: Euclidean::split(e2i).ep,
+            e2n: Euclidean::split(e2i).en,
+            e3p: Euclidean::split(e3i).ep,
+            e3n: Euclidean::split(e3i).en,
+            e123p: Euclidean::split(e123i).ep,
+            e123n: Euclidean::split(e123i).en,
+            ..zero()
+        }
+    }
+
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        Euclidean::joins(v.e1p, v.e1n)
+            && Euclidean::joins(v.e2p, v.e2n)
+            && Euclidean::joins(v.e3p, v.e3n)
+            && Euclidean::joins(v.e123p, v.e123n)
+    }
+
+    #[inline]
+    fn from_mv(v: R410<T>) -> Self {
+        let R410 {
+            s,
+            e12,
+            e13,
+            e23,
+            e1p,
+            e1n,
+            e2p,
+            e2n,
+            e3p,
+            e3n,
+            e123p,
+            e123n,
+            ..
+        } = v;
+        Self {
+            s,
+            e12,
+            e13,
+            e23,
+            e1i: Euclidean::join(e1p, e1n),
+            e2i: Euclidean::join(e2p, e2n),
+            e3i: Euclidean::join(e3p, e3n),
+            e123i: Euclidean::join(e123p, e123n),
+        }
+    }
+}
+
+impl<T: Field + Copy> Motor<T> {
+    /// Constructs the motor that rotates by `rotor` and then translates by `translation`.
+    #[inline]
+    pub fn new(rotor: Rotor<T>, translation: Vector<T>) -> Self {
+        translation.into_translator::<Euclidean>() * rotor
+    }
+
+    /// The rotational part of the motor.
+    #[inline]
+    pub fn rotor(self) -> Rotor<T> {
+        Rotor::from_mv(self.into_mv())
+    }
+
+    /// The translation applied after [`Motor::rotor`].
+    #[inline]
+    pub fn translation(self) -> Vector<T> {
+        let r = self.rotor().into_mv();
+        let t = Translator::<T>::from_mv(self.into_mv() * r.reverse() / r.norm_squared());
+        let n2 = T::from_subset(&-2.0);
+        Vector::new(t.e1i * n2, t.e2i * n2, t.e3i * n2)
+    }
+
+    /// Converts the motor into a row-major homogeneous matrix acting on column vectors.
+    pub fn to_matrix4(self) -> [[T; 4]; 4] {
+        let r = self.rotor().normalize().to_matrix3();
+        let t = self.translation();
+        let (zero, one) = (T::zero(), T::one());
+        [
+            [r[0][0], r[0][1], r[0][2], t.e1],
+            [r[1][0], r[1][1], r[1][2], t.e2],
+            [r[2][0], r[2][1], r[2][2], t.e3],
+            [zero, zero, zero, one],
+        ]
+    }
+}
+
+impl<T: RealField + Copy> Motor<T> {
+    /// Constructs a motor from a row-major homogeneous matrix acting on column vectors.
+    ///
+    /// Fails with `Error::NotVersor` unless the matrix is a rigid transform: the upper 3x3 block
+    /// must be orthonormal with a positive determinant and the bottom row must be `[0, 0, 0, 1]`.
+    /// Both checks use a tolerance of `1e-5`. They only involve entries that are at most one for
+    /// a rigid transform, so the tolerance is relative to them, and the translation column can be
+    /// arbitrarily large.
+    pub fn try_from_matrix4(m: [[T; 4]; 4]) -> Result<Self, Error> {
+        let (zero, one) = (T::zero(), T::one());
+        let tol = T::from_subset(&1e-5);
+        let near = |a: T, b: T| (a - b).abs() <= tol;
+        let bottom = [zero, zero, zero, one];
+        if !m[3].iter().zip(bottom.iter()).all(|(&a, &b)| near(a, b)) {
+            return Err(Error::NotVersor);
+        }
+
+        let col = |j: usize| Vector::new(m[0][j], m[1][j], m[2][j]);
+        let cols = [col(0), col(1), col(2)];
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { one } else { zero };
+                if !near(cols[i].dot(cols[j]), expected) {
+                    return Err(Error::NotVersor);
+                }
+            }
+        }
+        let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        if det <= zero {
+            return Err(Error::NotVersor);
+        }
+
+        let r = [
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ];
+        let rotor = Rotor::from_matrix3(r).normalize();
+        Ok(Self::new(rotor, Vector::new(m[0][3], m[1][3], m[2][3])))
+    }
+
+    /// Like [`Motor::try_from_matrix4`], but returns `None` for matrices that aren't rigid
+    /// transforms.
+    #[inline]
+    pub fn from_matrix4(m: [[T; 4]; 4]) -> Option<Self> {
+        Self::try_from_matrix4(m).ok()
+    }
+}
+
+impl<T: Field + Copy> From<Rotor<T>> for Motor<T> {
+    #[inline]
+    fn from(r: Rotor<T>) -> Self {
+        Self::from_mv(r.into_mv())
+    }
+}
+
+impl<T: Field + Copy> From<Translator<T>> for Motor<T> {
+    #[inline]
+    fn from(t: Translator<T>) -> Self {
+        Self::from_mv(t.into_mv())
+    }
+}
+
+impl<T: Field + Copy> Transform<Point<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<Pair<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<Circle<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<Sphere<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<FPoint<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<Line<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<Plane<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<DLine<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<DPlane<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<DSphere<T>> for Motor<T> {}
+impl<T: Field + Copy> Transform<DVector<T>> for Motor<T> {}
+
+impl<T: Field + Copy> Mul for Motor<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_mv(self.into_mv() * rhs.into_mv())
+    }
+}
+
+macro_rules! impl_compose {
+    ($($lhs:ident * $rhs:ident),*) => {
+        $(
+            impl<T: Field + Copy> Mul<$rhs<T>> for $lhs<T> {
+                type Output = Motor<T>;
+                #[inline]
+                fn mul(self, rhs: $rhs<T>) -> Motor<T> {
+                    Motor::from_mv(self.into_mv() * rhs.into_mv())
+                }
+            }
+        )*
+    };
+}
+
+impl_compose!(
+    Rotor * Translator,
+    Translator * Rotor,
+    Motor * Rotor,
+    Motor * Translator,
+    Rotor * Motor,
+    Translator * Motor
+);
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{assert_transforms_flats, assert_transforms_rounds};
+    use super::*;
+    use super::super::DualQuaternion;
+    use crate::d3::free::{Bivector, Vector};
+    use crate::{Normalize, Outer};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_sandwich() {
+        let r = Bivector { e12: 1.0, e13: -0.5, e23: 2.0 }.with_angle(0.8);
+        let t = Vector::new(1.0, -3.0, 2.0).into_translator();
+        let m = Motor::from(t) * Motor::from(r);
+        assert_transforms_rounds::<Euclidean, _>(m);
+        assert_transforms_flats::<Euclidean, _>(m);
+    }
+
+    fn assert_point_eq(a: Point<f64>, b: Point<f64>) {
+        assert_relative_eq!(a.normalized().dot(b.normalized()), 0.0, epsilon = 1e-9);
+    }
+
+    fn assert_line_eq(a: Line<f64>, b: Line<f64>) {
+        for (x, y) in a.into_mv().into_array().iter().zip(b.into_mv().into_array().iter()) {
+            assert_relative_eq!(x, y, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rotation_direction() {
+        let r = Bivector { e12: 1.0, e13: 0.0, e23: 0.0 }.with_angle(core::f64::consts::FRAC_PI_2);
+        let v = r.transform(Vector::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(v.x(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(v.y(), 1.0, epsilon = 1e-12);
+        let p = r.transform(Point::<f64>::new([1.0, 0.0, 0.0]));
+        assert_point_eq(p, Point::new([0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_composition() {
+        let r1 = Bivector { e12: 1.0, e13: -0.5, e23: 2.0 }.with_angle(0.8);
+        let r2 = Bivector { e12: 0.0, e13: 1.0, e23: 0.3 }.with_angle(-1.9);
+        let t1: Translator<f64> = Vector::new(1.0, -3.0, 2.0).into_translator();
+        let t2: Translator<f64> = Vector::new(-0.5, 0.0, 4.0).into_translator();
+
+        let p = Point::<f64>::new([2.0, -1.0, 0.5]);
+        let l = p.outer(Point::new([0.0, 3.0, 1.0])).extend();
+
+        assert_point_eq((r1 * r2).transform(p), r1.transform(r2.transform(p)));
+        assert_point_eq((t1 * t2).transform(p), t1.transform(t2.transform(p)));
+        assert_point_eq((r1 * t1).transform(p), r1.transform(t1.transform(p)));
+        assert_point_eq((t1 * r1).transform(p), t1.transform(r1.transform(p)));
+
+        let m1 = t1 * r1;
+        let m2 = r2 * t2;
+        assert_point_eq((m1 * m2).transform(p), m1.transform(m2.transform(p)));
+        assert_point_eq((m1 * r2).transform(p), m1.transform(r2.transform(p)));
+        assert_point_eq((r2 * m1).transform(p), r2.transform(m1.transform(p)));
+        assert_point_eq((m1 * t2).transform(p), m1.transform(t2.transform(p)));
+        assert_point_eq((t2 * m1).transform(p), t2.transform(m1.transform(p)));
+        assert_line_eq((m1 * m2).transform(l), m1.transform(m2.transform(l)));
+
+        // Translating then rotating about the origin isn't the same as the reverse.
+        let moved = Point::<f64>::new([1.0, 0.0, 0.0]);
+        let r = Bivector { e12: 1.0, e13: 0.0, e23: 0.0 }.with_angle(core::f64::consts::FRAC_PI_2);
+        let t: Translator<f64> = Vector::new(1.0, 0.0, 0.0).into_translator();
+        assert_point_eq((r * t).transform(Point::new([0.0, 0.0, 0.0])), Point::new([0.0, 1.0, 0.0]));
+        assert_point_eq((t * r).transform(Point::new([0.0, 0.0, 0.0])), moved);
+    }
+
+    fn motor() -> Motor<f64> {
+        let r = Rotor::from_axis_angle(Vector::new(1.0, -2.0, 0.5), 2.3);
+        Motor::new(r, Vector::new(3.0, -1.0, 0.25))
+    }
+
+    #[test]
+    fn test_parts() {
+        let r = Rotor::from_axis_angle(Vector::new(1.0, -2.0, 0.5), 2.3);
+        let t = Vector::new(3.0, -1.0, 0.25);
+        let m = Motor::new(r, t);
+        assert_relative_eq!(m.translation().x(), t.x(), epsilon = 1e-12);
+        assert_relative_eq!(m.translation().y(), t.y(), epsilon = 1e-12);
+        assert_relative_eq!(m.translation().z(), t.z(), epsilon = 1e-12);
+        assert_eq!(m.rotor().into_mv(), r.into_mv());
+
+        let p = Point::<f64>::new([0.5, 1.0, -2.0]);
+        let expected = t.into_translator::<Euclidean>().transform(r.transform(p));
+        assert_point_eq(m.transform(p), expected);
+    }
+
+    #[test]
+    fn test_matrix4() {
+        let m = motor();
+        let mat = m.to_matrix4();
+        assert_eq!(mat[3], [0.0, 0.0, 0.0, 1.0]);
+        let x = [0.5, 1.0, -2.0];
+        let row = |i: usize| mat[i][0] * x[0] + mat[i][1] * x[1] + mat[i][2] * x[2] + mat[i][3];
+        assert_point_eq(m.transform(Point::new(x)), Point::new([row(0), row(1), row(2)]));
+
+        let back = Motor::from_matrix4(mat).unwrap();
+        assert_point_eq(back.transform(Point::new(x)), m.transform(Point::new(x)));
+        for (a, b) in back.to_matrix4().iter().zip(mat.iter()) {
+            for (a, b) in a.iter().zip(b.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix4_rejects_non_rigid() {
+        let mat = motor().to_matrix4();
+
+        let mut scaled = mat;
+        scaled[0][0] *= 2.0;
+        scaled[1][0] *= 2.0;
+        scaled[2][0] *= 2.0;
+        assert!(Motor::from_matrix4(scaled).is_none());
+
+        let mut sheared = mat;
+        sheared[0][1] += 0.1;
+        assert!(Motor::from_matrix4(sheared).is_none());
+
+        let mut reflected = mat;
+        for row in reflected.iter_mut().take(3) {
+            row[2] = -row[2];
+        }
+        assert!(Motor::from_matrix4(reflected).is_none());
+
+        let mut projective = mat;
+        projective[3][0] = 0.5;
+        assert!(Motor::from_matrix4(projective).is_none());
+
+        let identity = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        assert_eq!(Motor::from_matrix4(identity).unwrap().into_mv(), Rotor::identity().into_mv());
+    }
+
+    #[test]
+    fn test_matrix4_large_translation() {
+        let mut far = motor().to_matrix4();
+        for row in far.iter_mut().take(3) {
+            row[3] *= 1e6;
+        }
+        assert!(Motor::try_from_matrix4(far).is_ok());
+
+        // The translation doesn't loosen the checks on the rest of the matrix.
+        let mut scaled = far;
+        for row in scaled.iter_mut().take(3) {
+            for x in row.iter_mut().take(3) {
+                *x *= 3.0;
+            }
+        }
+        assert_eq!(Motor::try_from_matrix4(scaled).unwrap_err(), Error::NotVersor);
+
+        let mut sheared = far;
+        sheared[0][1] += 1e-3;
+        assert_eq!(Motor::try_from_matrix4(sheared).unwrap_err(), Error::NotVersor);
+
+        let mut projective = far;
+        projective[3][0] = 1e-4;
+        assert_eq!(Motor::try_from_matrix4(projective).unwrap_err(), Error::NotVersor);
+    }
+
+    #[test]
+    fn test_dual_quaternion() {
+        // Quaternion product of [w, x, y, z] arrays.
+        fn qmul(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+            [
+                a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],
+                a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],
+                a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],
+                a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0],
+            ]
+        }
+
+        let r = Rotor::from_axis_angle(Vector::new(1.0, -2.0, 0.5), 2.3);
+        let t = Vector::new(3.0, -1.0, 0.25);
+        let dq = DualQuaternion::from(Motor::new(r, t));
+        let real = r.to_quaternion();
+        let dual = qmul([0.0, t.x() * 0.5, t.y() * 0.5, t.z() * 0.5], real);
+        for (a, b) in dq.real.iter().chain(dq.dual.iter()).zip(real.iter().chain(dual.iter())) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+
+        // Lossless for any motor, not only normalized ones.
+        let m = Motor {
+            s: 1.0,
+            e12: 2.0,
+            e13: 3.0,
+            e23: 4.0,
+            e1i: 5.0,
+            e2i: 6.0,
+            e3i: 7.0,
+            e123i: 8.0,
+        };
+        assert_eq!(Motor::from(DualQuaternion::from(m)).into_mv(), m.into_mv());
+    }
+}
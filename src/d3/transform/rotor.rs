@@ -2,18 +2,28 @@ use core::ops::Mul;
 
 use num_traits::zero;
 
-use super::super::free::Vector;
+use simba::scalar::RealField;
+
+use super::super::direction::DVector;
+use super::super::dual::{DLine, DPlane, DSphere};
+use super::super::flat::{FPoint, Line, Plane};
+use super::super::free::{Bivector, Trivector, Vector};
+use super::super::round::{Circle, Pair, Point, Sphere};
 use super::Transform;
-use crate::{Field, Multivec, R410};
+use crate::{Error, Field, Multivec, Space, R410};
 
-#[derive(Copy, Clone, Debug)]
-pub struct Rotor<T: Field> {
+/// Laid out in memory as `[s, e12, e13, e23]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Rotor<T> {
     pub(crate) s: T,
     pub(crate) e12: T,
     pub(crate) e13: T,
     pub(crate) e23: T,
 }
 
+impl_traits!(Rotor{s, e12, e13, e23, []});
+
 impl<T: Field + Copy> Multivec for Rotor<T> {
     type Element = T;
     #[inline]
@@ -37,14 +47,248 @@ impl<T: Field + Copy> Multivec for Rotor<T> {
     }
 }
 
-impl<T: Field + Copy> Transform<Vector<T>> for Rotor<T> {
+/// The order in which [`Rotor::from_euler`] and [`Rotor::to_euler`] apply rotations about the
+/// coordinate axes. `XYZ` builds `Rx(a) * Ry(b) * Rz(c)`: intrinsic rotations about X, then the
+/// rotated Y, then the rotated Z, or equivalently extrinsic rotations about Z, Y and then X.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// The indices of the three axes and whether they form an even permutation.
+    fn axes(self) -> ([usize; 3], bool) {
+        match self {
+            EulerOrder::XYZ => ([0, 1, 2], true),
+            EulerOrder::YZX => ([1, 2, 0], true),
+            EulerOrder::ZXY => ([2, 0, 1], true),
+            EulerOrder::XZY => ([0, 2, 1], false),
+            EulerOrder::YXZ => ([1, 0, 2], false),
+            EulerOrder::ZYX => ([2, 1, 0], false),
+        }
+    }
+}
+
+impl<T: Field + Copy> Rotor<T> {
+    /// The rotor that leaves every object unchanged.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            s: T::one(),
+            e12: zero(),
+            e13: zero(),
+            e23: zero(),
+        }
+    }
+
+    /// Constructs a right-handed rotation of `angle` radians about `axis`.
+    /// `axis` doesn't need to be normalized.
+    #[inline]
+    pub fn from_axis_angle(axis: Vector<T>, angle: T) -> Self {
+        let (sin, cos) = (angle * T::from_subset(&0.5)).simd_sin_cos();
+        let axis = axis.normalize() * sin;
+        Self::from_quaternion([cos, axis.e1, axis.e2, axis.e3])
+    }
+
+    /// Constructs a rotor from a quaternion given as `[w, x, y, z]`.
+    ///
+    /// Both represent the same rotation, with `s = w`, `e23 = -x`, `e13 = y` and `e12 = -z`,
+    /// so the quaternion `i` corresponds to the plane orthogonal to the X axis, and so on.
+    #[inline]
+    pub fn from_quaternion(q: [T; 4]) -> Self {
+        let [w, x, y, z] = q;
+        Self {
+            s: w,
+            e12: -z,
+            e13: y,
+            e23: -x,
+        }
+    }
+
+    /// Converts the rotor into a quaternion `[w, x, y, z]`. See [`Rotor::from_quaternion`].
     #[inline]
-    fn transform(self, p: Vector<T>) -> Vector<T> {
-        let t = self.into_mv();
-        Vector::from_mv(t.reverse() * p.into_mv() * t)
+    pub fn to_quaternion(self) -> [T; 4] {
+        [self.s, -self.e23, self.e13, -self.e12]
+    }
+
+    /// Converts a normalized rotor into a row-major rotation matrix acting on column vectors.
+    pub fn to_matrix3(self) -> [[T; 3]; 3] {
+        let [w, x, y, z] = self.to_quaternion();
+        let one = T::one();
+        let two = T::from_subset(&2.0);
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    #[inline]
+    pub fn norm_squared(self) -> T {
+        self.s * self.s + self.e12 * self.e12 + self.e13 * self.e13 + self.e23 * self.e23
+    }
+
+    #[inline]
+    pub fn norm(self) -> T {
+        self.norm_squared().simd_sqrt()
+    }
+
+    /// Scales the rotor to unit norm so that it doesn't scale the objects it transforms.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self::from_mv(self.into_mv() / self.norm())
+    }
+
+    /// The rotor that undoes this one.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Self::from_mv(self.into_mv().reverse() / self.norm_squared())
+    }
+
+    /// The rotor that undoes this one, or `Error::Degenerate` for the zero rotor.
+    #[inline]
+    pub fn try_inverse(self) -> Result<Self, Error> {
+        let norm_squared = self.norm_squared();
+        if norm_squared.is_zero() {
+            Err(Error::Degenerate)
+        } else {
+            Ok(Self::from_mv(self.into_mv().reverse() / norm_squared))
+        }
+    }
+
+    /// The angle of rotation in radians, between 0 and 2π.
+    #[inline]
+    pub fn angle(self) -> T {
+        let sin = (self.e12 * self.e12 + self.e13 * self.e13 + self.e23 * self.e23).simd_sqrt();
+        sin.simd_atan2(self.s) * T::from_subset(&2.0)
+    }
+}
+
+impl<T: RealField + Copy> Rotor<T> {
+    /// The unit axis of rotation, following the right-hand rule with [`Rotor::angle`].
+    /// Returns `None` for the identity, which has no axis.
+    pub fn axis(self) -> Option<Vector<T>> {
+        let [_, x, y, z] = self.to_quaternion();
+        let axis = Vector::new(x, y, z);
+        if axis.norm_squared().is_zero() {
+            None
+        } else {
+            Some(axis.normalize())
+        }
+    }
+
+    /// Constructs a rotor from a row-major rotation matrix acting on column vectors.
+    ///
+    /// Uses Shepperd's method, which takes the square root of whichever of the quaternion
+    /// components is largest so that the divisions are always well conditioned.
+    pub fn from_matrix3(m: [[T; 3]; 3]) -> Self {
+        let one = T::one();
+        let quarter = T::from_subset(&0.25);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let w4 = (one + trace).sqrt() * T::from_subset(&2.0);
+            [
+                w4 * quarter,
+                (m[2][1] - m[1][2]) / w4,
+                (m[0][2] - m[2][0]) / w4,
+                (m[1][0] - m[0][1]) / w4,
+            ]
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let x4 = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * T::from_subset(&2.0);
+            [
+                (m[2][1] - m[1][2]) / x4,
+                x4 * quarter,
+                (m[0][1] + m[1][0]) / x4,
+                (m[0][2] + m[2][0]) / x4,
+            ]
+        } else if m[1][1] >= m[2][2] {
+            let y4 = (one - m[0][0] + m[1][1] - m[2][2]).sqrt() * T::from_subset(&2.0);
+            [
+                (m[0][2] - m[2][0]) / y4,
+                (m[0][1] + m[1][0]) / y4,
+                y4 * quarter,
+                (m[1][2] + m[2][1]) / y4,
+            ]
+        } else {
+            let z4 = (one - m[0][0] - m[1][1] + m[2][2]).sqrt() * T::from_subset(&2.0);
+            [
+                (m[1][0] - m[0][1]) / z4,
+                (m[0][2] + m[2][0]) / z4,
+                (m[1][2] + m[2][1]) / z4,
+                z4 * quarter,
+            ]
+        };
+        Self::from_quaternion(q)
+    }
+
+    /// Constructs a rotor from three angles in radians, applied in the given order.
+    pub fn from_euler(order: EulerOrder, angles: [T; 3]) -> Self {
+        let ([i, j, k], _) = order.axes();
+        let axis = |a: usize| {
+            let mut v = [T::zero(); 3];
+            v[a] = T::one();
+            Vector::from(v)
+        };
+        Self::from_axis_angle(axis(i), angles[0])
+            * Self::from_axis_angle(axis(j), angles[1])
+            * Self::from_axis_angle(axis(k), angles[2])
+    }
+
+    /// Decomposes the rotor into three angles in radians, such that
+    /// `Rotor::from_euler(order, r.to_euler(order))` is the same rotation as `r`.
+    ///
+    /// The middle angle is within ±π/2. At gimbal lock, when it's exactly ±π/2, the last angle is
+    /// set to zero.
+    pub fn to_euler(self, order: EulerOrder) -> [T; 3] {
+        let ([i, j, k], even) = order.axes();
+        let m = self.normalize().to_matrix3();
+        let sign = if even { T::one() } else { -T::one() };
+        let sin_b = (m[i][k] * sign).clamp(-T::one(), T::one());
+        let b = sin_b.asin();
+        if sin_b.abs() < T::one() - T::default_epsilon() * T::from_subset(&16.0) {
+            let a = (-sign * m[j][k]).atan2(m[k][k]);
+            let c = (-sign * m[i][j]).atan2(m[i][i]);
+            [a, b, c]
+        } else {
+            let a = (sin_b.signum() * m[j][i]).atan2(m[j][j]);
+            [a, b, T::zero()]
+        }
     }
 }
 
+impl<T: Field + Copy> Transform<Vector<T>> for Rotor<T> {}
+impl<T: Field + Copy> Transform<Bivector<T>> for Rotor<T> {}
+impl<T: Field + Copy> Transform<Trivector<T>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Point<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Pair<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Circle<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Sphere<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<FPoint<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Line<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<Plane<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<DLine<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<DPlane<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<DSphere<T, S>> for Rotor<T> {}
+impl<T: Field + Copy, S: Space> Transform<DVector<T, S>> for Rotor<T> {}
+
 impl<T: Field + Copy> Mul for Rotor<T> {
     type Output = Self;
     #[inline]
@@ -52,3 +296,110 @@ impl<T: Field + Copy> Mul for Rotor<T> {
         Self::from_mv(self.into_mv() * rhs.into_mv())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{assert_sandwich, assert_transforms_flats, assert_transforms_rounds};
+    use super::*;
+    use crate::{Euclidean, Hyperbolic, Spherical};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_sandwich() {
+        let r = Bivector { e12: 1.0, e13: -0.5, e23: 2.0 }.with_angle(0.8);
+        assert_sandwich(r, Bivector { e12: 0.5, e13: 2.0, e23: -1.0 });
+        assert_sandwich(r, Trivector { e123: 3.0 });
+        assert_transforms_rounds::<Euclidean, _>(r);
+        assert_transforms_rounds::<Hyperbolic, _>(r);
+        assert_transforms_rounds::<Spherical, _>(r);
+        assert_transforms_flats::<Euclidean, _>(r);
+        assert_transforms_flats::<Hyperbolic, _>(r);
+        assert_transforms_flats::<Spherical, _>(r);
+    }
+
+    fn assert_same_rotation(a: Rotor<f64>, b: Rotor<f64>) {
+        // q and -q are the same rotation.
+        let dot = a.s * b.s + a.e12 * b.e12 + a.e13 * b.e13 + a.e23 * b.e23;
+        assert_relative_eq!(dot.abs(), 1.0, epsilon = 1e-9);
+    }
+
+    fn rotors() -> Vec<Rotor<f64>> {
+        let mut rotors = vec![Rotor::identity()];
+        for axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, -2.0, 0.5]] {
+            for angle in [0.3, -1.2, 2.5, 3.1, core::f64::consts::PI] {
+                rotors.push(Rotor::from_axis_angle(Vector::from(axis), angle));
+            }
+        }
+        rotors
+    }
+
+    #[test]
+    fn test_quaternion() {
+        let r = Bivector { e12: 1.0, e13: 0.0, e23: 0.0 }.with_angle(1.0);
+        let [w, x, y, z] = r.to_quaternion();
+        assert_relative_eq!(w, 0.5f64.cos());
+        assert_relative_eq!(x, 0.0);
+        assert_relative_eq!(y, 0.0);
+        assert_relative_eq!(z, 0.5f64.sin());
+        for r in rotors() {
+            assert_same_rotation(Rotor::from_quaternion(r.to_quaternion()), r);
+        }
+    }
+
+    #[test]
+    fn test_matrix3() {
+        let v = Vector::new(0.5, -1.0, 2.0);
+        for r in rotors() {
+            let m = r.to_matrix3();
+            let rotated = r.transform(v);
+            for (row, expected) in m.iter().zip([rotated.x(), rotated.y(), rotated.z()]) {
+                let actual = row[0] * v.x() + row[1] * v.y() + row[2] * v.z();
+                assert_relative_eq!(actual, expected, epsilon = 1e-9);
+            }
+            assert_same_rotation(Rotor::from_matrix3(m), r);
+        }
+    }
+
+    #[test]
+    fn test_euler() {
+        use EulerOrder::*;
+        for order in [XYZ, XZY, YXZ, YZX, ZXY, ZYX] {
+            for r in rotors() {
+                assert_same_rotation(Rotor::from_euler(order, r.to_euler(order)), r);
+            }
+            let angles = [0.4, -0.9, 2.0];
+            let euler = Rotor::from_euler(order, angles).to_euler(order);
+            for (a, b) in euler.iter().zip(angles.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-9);
+            }
+            for b in [core::f64::consts::FRAC_PI_2, -core::f64::consts::FRAC_PI_2] {
+                let locked = Rotor::from_euler(order, [0.7, b, -0.2]);
+                assert_same_rotation(Rotor::from_euler(order, locked.to_euler(order)), locked);
+            }
+        }
+        let yaw = Rotor::from_euler(ZYX, [0.5, 0.0, 0.0]);
+        assert_same_rotation(yaw, Rotor::from_axis_angle(Vector::new(0.0, 0.0, 1.0), 0.5));
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let axis = Vector::new(1.0, -2.0, 0.5);
+        let r = Rotor::from_axis_angle(axis * 3.0, 1.3);
+        assert_relative_eq!(r.angle(), 1.3, epsilon = 1e-12);
+        let a = r.axis().unwrap();
+        let n = axis.normalize();
+        assert_relative_eq!(a.x(), n.x(), epsilon = 1e-12);
+        assert_relative_eq!(a.y(), n.y(), epsilon = 1e-12);
+        assert_relative_eq!(a.z(), n.z(), epsilon = 1e-12);
+        assert!(Rotor::<f64>::identity().axis().is_none());
+
+        let scaled = Rotor::from_mv(r.into_mv() * 3.0);
+        assert_relative_eq!(scaled.norm(), 3.0, epsilon = 1e-12);
+        assert_same_rotation(scaled.normalize(), r);
+        let v = Vector::new(0.5, -1.0, 2.0);
+        let back = (scaled.inverse() * scaled).transform(v);
+        assert_relative_eq!(back.x(), v.x(), epsilon = 1e-12);
+        assert_relative_eq!(back.y(), v.y(), epsilon = 1e-12);
+        assert_relative_eq!(back.z(), v.z(), epsilon = 1e-12);
+    }
+}
@@ -5,14 +5,18 @@ use num_traits::zero;
 
 use simba::simd::SimdRealField as Field;
 
-use super::super::{Point, Sphere};
-use super::super::dual::DSphere;
+use super::super::direction::DVector;
+use super::super::dual::{DLine, DPlane, DSphere};
+use super::super::flat::{FPoint, Line, Plane};
+use super::super::round::{Circle, Pair, Point, Sphere};
 //use super::super::tangent::{TVector, TBivector};
 use super::Transform;
 use crate::{Euclidean, Multivec, Space, R410};
 
+/// Laid out in memory as `[e1i, e2i, e3i, s]`.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Translator<T: Field, S = Euclidean> {
+#[repr(C)]
+pub struct Translator<T, S = Euclidean> {
     pub(crate) e1i: T,
     pub(crate) e2i: T,
     pub(crate) e3i: T,
@@ -20,6 +24,8 @@ pub struct Translator<T: Field, S = Euclidean> {
     _pd: PhantomData<S>,
 }
 
+impl_traits!(Translator<S>{e1i, e2i, e3i, s, [_pd: PhantomData]});
+
 impl<T: Field + Copy, S: Space> Multivec for Translator<T, S> {
     type Element = T;
     #[inline]
@@ -43,6 +49,14 @@ impl<T: Field + Copy, S: Space> Multivec for Translator<T, S> {
         }
     }
 
+    #[inline]
+    fn joins(v: &R410<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        S::joins(v.e1p, v.e1n) && S::joins(v.e2p, v.e2n) && S::joins(v.e3p, v.e3n)
+    }
+
     #[inline]
     fn from_mv(v: R410<T>) -> Self {
         let R410 {
@@ -65,9 +79,20 @@ impl<T: Field + Copy, S: Space> Multivec for Translator<T, S> {
     }
 }
 
-impl<T: Field + Copy, S: Space> Transform<Sphere<T>> for Translator<T, S> {}
-impl<T: Field + Copy, S: Space> Transform<DSphere<T>> for Translator<T, S> {}
-impl<T: Field + Copy, S: Space> Transform<Point<T>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<Point<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<Pair<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<Circle<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<Sphere<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<DLine<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<DPlane<T, S>> for Translator<T, S> {}
+impl<T: Field + Copy, S: Space> Transform<DSphere<T, S>> for Translator<T, S> {}
+
+// Flats contain the point at infinity, which is only fixed by translations when it's null.
+// In curved spaces the result picks up components the flat types can't represent.
+impl<T: Field + Copy> Transform<FPoint<T>> for Translator<T> {}
+impl<T: Field + Copy> Transform<Line<T>> for Translator<T> {}
+impl<T: Field + Copy> Transform<Plane<T>> for Translator<T> {}
+impl<T: Field + Copy> Transform<DVector<T>> for Translator<T> {}
 //impl<T: Field + Copy, S: Space> Transform<TVector<T>> for Translator<T, S> {}
 //impl<T: Field + Copy, S: Space> Transform<TBivector<T>> for Translator<T, S> {}
 
@@ -88,6 +113,7 @@ impl<T: Field + Copy, S: Space> Mul<T> for Translator<T, S> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use super::super::test::{assert_transforms_flats, assert_transforms_rounds};
     use crate::d3::free::Vector;
     #[test]
     fn test_from_vec() {
@@ -155,4 +181,14 @@ mod test {
             (v3.into_point::<Euclidean>().into_flat() * v2.into_point().into_flat())
         );
     }
+
+    #[test]
+    fn test_sandwich() {
+        use crate::{Hyperbolic, Spherical};
+        let v = Vector::new(1.0, -3.0, 2.0);
+        assert_transforms_rounds::<Euclidean, _>(v.into_translator::<Euclidean>());
+        assert_transforms_rounds::<Hyperbolic, _>(v.into_translator::<Hyperbolic>());
+        assert_transforms_rounds::<Spherical, _>(v.into_translator::<Spherical>());
+        assert_transforms_flats::<Euclidean, _>(v.into_translator::<Euclidean>());
+    }
 }
@@ -0,0 +1,95 @@
+//! Errors from operations that are only defined for some objects.
+
+use core::fmt::{self, Display, Formatter};
+
+/// The reason a fallible `try_` operation failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The object is zero or has collapsed to a lower dimension, so it has no direction or size.
+    Degenerate,
+    /// The result lies at infinity and has no finite position.
+    PointAtInfinity,
+    /// The result is imaginary, such as the radius of an imaginary sphere or the points of an
+    /// imaginary pair.
+    Imaginary,
+    /// The input doesn't describe a versor of the requested kind, such as a matrix that isn't a
+    /// rigid transform.
+    NotVersor,
+    /// The `ep` and `en` halves of a component don't combine into a single multiple of infinity in
+    /// the object's space.
+    InconsistentNull,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Degenerate => "degenerate object",
+            Error::PointAtInfinity => "point at infinity",
+            Error::Imaginary => "imaginary result",
+            Error::NotVersor => "not a versor",
+            Error::InconsistentNull => "inconsistent null components",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::dual::DSphere;
+    use crate::d3::free::Vector;
+    use crate::d3::round::{Pair, Point};
+    use crate::d3::transform::{Motor, Rotor};
+    use crate::{Euclidean, Hyperbolic, Multivec, Outer, Space, R410};
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(Vector::new(0.0, 0.0, 0.0).try_normalize(), Err(Error::Degenerate));
+        assert_eq!(Vector::new(0.0, 0.0, 4.0).try_normalize(), Ok(Vector::new(0.0, 0.0, 1.0)));
+        assert_eq!(Rotor::from_mv(R410::<f64>::default()).try_inverse(), Err(Error::Degenerate));
+        assert!(Rotor::<f64>::identity().try_inverse().is_ok());
+    }
+
+    #[test]
+    fn test_point_at_infinity() {
+        let ni = Point::<f64>::from_mv(Euclidean::infinity());
+        assert_eq!(ni.try_position(), Err(Error::PointAtInfinity));
+        let p = Point::<f64>::new([1.0, 2.0, 3.0]);
+        assert_eq!(p.try_position(), Ok(Vector::new(1.0, 2.0, 3.0)));
+        assert_eq!(p.outer(ni).try_decompose(), Err(Error::PointAtInfinity));
+    }
+
+    #[test]
+    fn test_imaginary() {
+        let p = Point::<f64>::new([1.0, 2.0, 3.0]);
+        assert_eq!(p.into_sphere(2.0).try_radius(), Ok(2.0));
+        let imaginary = DSphere::<f64>::from_mv(p.into_mv() + Euclidean::infinity::<f64>() * 2.0);
+        assert_eq!(imaginary.try_radius(), Err(Error::Imaginary));
+
+        // The dual of a real circle is an imaginary pair.
+        let pair = p.outer(Point::new([-1.0, 0.0, 0.0]));
+        assert!(pair.try_decompose().is_ok());
+        let circle = pair.outer(Point::new([0.0, 1.0, 0.0]));
+        let imaginary = Pair::<f64>::from_mv(circle.into_mv().dual());
+        assert_eq!(imaginary.try_decompose(), Err(Error::Imaginary));
+    }
+
+    #[test]
+    fn test_not_versor() {
+        let mut m = Motor::new(Rotor::<f64>::identity(), Vector::new(1.0, 2.0, 3.0)).to_matrix4();
+        assert!(Motor::try_from_matrix4(m).is_ok());
+        m[0][0] = 2.0;
+        assert_eq!(Motor::try_from_matrix4(m), Err(Error::NotVersor));
+    }
+
+    #[test]
+    fn test_inconsistent_null() {
+        assert_eq!(Euclidean::try_join(2.0, 2.0), Ok(2.0));
+        assert_eq!(Euclidean::try_join(1.0, 2.0), Err(Error::InconsistentNull));
+        assert_eq!(Euclidean::join(1.0, 1.5), 1.25);
+        assert_eq!(Hyperbolic::try_join(1.0, 0.0), Ok(1.0));
+        assert_eq!(Hyperbolic::try_join(1.0, 2.0), Err(Error::InconsistentNull));
+        assert_eq!(Error::InconsistentNull.to_string(), "inconsistent null components");
+    }
+}
@@ -1,346 +1,996 @@
-use super::F32;
-
-#[cfg_attr(feature = "repr_simd", repr(simd))]
-#[cfg_attr(not(feature = "repr_simd"), repr(align(32)))]
-#[derive(Copy, Clone, PartialEq, Debug)]
-pub struct F32xN<const N: usize>([f32; N]);
-
-#[cfg_attr(feature = "repr_simd", repr(simd))]
-#[cfg_attr(not(feature = "repr_simd"), repr(align(16)))]
+//! Fixed width SIMD vectors of fast math floats, allowing a single geometric object to
+//! represent `N` objects at once.
+
+use core::fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp};
+use core::intrinsics::{fadd_fast, fdiv_fast, fmul_fast, frem_fast, fsub_fast};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+    DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::{Num, One, Signed, Zero};
+use simba::scalar::{ComplexField, Field, RealField, SubsetOf};
+use simba::simd::{
+    SimdBool, SimdComplexField, SimdPartialOrd, SimdRealField, SimdSigned, SimdValue,
+};
+
+use super::{F32, F64};
+
+/// A vector of `N` booleans, the result of comparing two SIMD floats.
+#[repr(align(16))]
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct BoolxN<const N: usize>([bool; N]);
 
-impl<const N: usize> F32xN<N> {
-    #[inline]
-    pub fn new(x: [f32; N]) -> Self {
-        Self::try_new(x).expect("Fast math operations unsafe with infinity and NaN")
-    }
-
+impl<const N: usize> BoolxN<N> {
     #[inline]
-    pub fn splat(x: f32) -> Self {
-        Self::try_splat(x).expect("Fast math operations unsafe with infinity and NaN")
+    pub fn new(x: [bool; N]) -> Self {
+        Self(x)
     }
 
     #[inline]
-    pub fn try_new(x: [f32; N]) -> Option<Self> {
-        if x.iter().all(f32::is_finite) {
-            Some(F32xN(x))
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    pub fn try_splat(x: f32) -> Option<Self> {
-        if x.is_finite() {
-            Some(F32xN([x; N]))
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    pub fn into_inner(self) -> [f32; N] {
+    pub fn into_inner(self) -> [bool; N] {
         self.0
     }
 }
 
-macro_rules! impl_fmt {
-    ($($trait:path),*) => {$(
-        impl<const N: usize> $trait for F32xN<N> {
-            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                <[f32] as $trait>::fmt(&self.0[..], f)
-            }
-        }
-    )*};
-}
-
-impl_fmt!(Display, LowerExp, UpperExp);
-
-impl<const N: usize> Add for F32xN<N> {
+impl<const N: usize> BitAnd for BoolxN<N> {
     type Output = Self;
     #[inline]
-    fn add(mut self, rhs: Self) -> Self {
-    	self += rhs;
-    	self
+    fn bitand(mut self, rhs: Self) -> Self {
+        self &= rhs;
+        self
     }
 }
 
-impl<const N: usize> AddAssign for F32xN<N> {
+impl<const N: usize> BitAndAssign for BoolxN<N> {
     #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-        	self.0[i] = unsafe { Self(fadd_fast(self.0[i], rhs.0[i])) };
-    	}
+    fn bitand_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            self.0[i] = self.0[i] && rhs.0[i];
+        }
     }
 }
 
-impl<const N: usize> Sub for F32xN<N> {
+impl<const N: usize> BitOr for BoolxN<N> {
     type Output = Self;
     #[inline]
-    fn sub(self, rhs: Self) -> Self {
-        self -= rhs;
+    fn bitor(mut self, rhs: Self) -> Self {
+        self |= rhs;
         self
     }
 }
 
-impl<const N: usize> SubAssign for F32xN<N> {
+impl<const N: usize> BitOrAssign for BoolxN<N> {
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-        	self.0[i] = unsafe { Self(fsub_fast(self.0[i], rhs.0[i])) };
-    	}
+    fn bitor_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            self.0[i] = self.0[i] || rhs.0[i];
+        }
     }
 }
 
-impl<const N: usize> Mul for F32xN<N> {
+impl<const N: usize> BitXor for BoolxN<N> {
     type Output = Self;
     #[inline]
-    fn mul(mut self, rhs: Self) -> Self {
-        self *= rhs;
+    fn bitxor(mut self, rhs: Self) -> Self {
+        self ^= rhs;
         self
     }
 }
 
-impl<const N: usize> MulAssign for F32xN<N> {
+impl<const N: usize> BitXorAssign for BoolxN<N> {
     #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-        	self.0[i] = unsafe { Self(fmul_fast(self.0[i], rhs.0[i])) };
-    	}
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            self.0[i] = self.0[i] != rhs.0[i];
+        }
     }
 }
 
-impl<const N: usize> Div for F32xN<N> {
+impl<const N: usize> Not for BoolxN<N> {
     type Output = Self;
     #[inline]
-    fn div(mut self, rhs: Self) -> Self {
-        self /= rhs;
+    fn not(mut self) -> Self {
+        for i in 0..N {
+            self.0[i] = !self.0[i];
+        }
         self
     }
 }
 
-impl<const N: usize> DivAssign for F32xN<N> {
+impl<const N: usize> SimdValue for BoolxN<N> {
+    type Element = bool;
+    type SimdBool = Self;
     #[inline]
-    fn div_assign(&mut self, rhs: Self) {
-    	assert!(!rhs.0.iter().any(f32::is_zero), "Division by zero");
-    	for i in 0..N {
-        	self.0[i] = unsafe { Self(fdiv_fast(self.0[i], rhs.0[i])) };
-    	}
+    fn lanes() -> usize {
+        N
     }
-}
-
-impl<const N: usize> Rem for F32xN<N> {
-    type Output = Self;
     #[inline]
-    fn rem(mut self, rhs: Self) -> Self {
-        self %= rhs;
-        self
+    fn splat(val: bool) -> Self {
+        Self([val; N])
     }
-}
-
-impl<const N: usize> RemAssign for F32xN<N> {
     #[inline]
-    fn rem_assign(&mut self, rhs: Self) {
-    	assert!(!rhs.0.iter().any(f32::is_zero), "Division by zero");
-    	for i in 0..N {
-        	self.0[i] = unsafe { Self(frem_fast(self.0[i], rhs.0[i])) };
-    	}
+    fn extract(&self, i: usize) -> bool {
+        self.0[i]
     }
-}
-
-impl<const N: usize> Neg for F32xN<N> {
-    type Output = Self;
     #[inline]
-    fn neg(self) -> Self {
-        Self([0.0; N]) - self
+    unsafe fn extract_unchecked(&self, i: usize) -> bool {
+        *self.0.get_unchecked(i)
     }
-}
-
-impl<const N: usize> Zero for F32xN<N> {
     #[inline]
-    fn zero() -> Self {
-        Self([0.0; N])
+    fn replace(&mut self, i: usize, val: bool) {
+        self.0[i] = val;
     }
     #[inline]
-    fn is_zero(&self) -> bool {
-    	self.0.iter().all(|x| x == 0.0 || x == -0.0)
+    unsafe fn replace_unchecked(&mut self, i: usize, val: bool) {
+        *self.0.get_unchecked_mut(i) = val;
     }
-}
-
-impl<const N: usize> One for F32xN<N> {
     #[inline]
-    fn one() -> Self {
-        Self([1.0; N])
-    }
-}
-
-impl<const N: usize> Num for F32xN<N> {
-    type FromStrRadixErr = <f32 as Num>::FromStrRadixErr;
-    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        f32::from_str_radix(str, radix).map(Self)
-    }
-}
-
-impl<const N: usize> SimdValue for F32xN<N> {
-    type Element = F32;
-    type SimdBool = BoolxN<N>;
-    fn lanes() -> usize {
-        N
-    }
-    fn splat(x: Self::Element) -> Self {
-        Self([x.0; N])
-    }
-    fn extract(&self, i: usize) -> Self::Element {
-        assert_lt!(i, N);
-        F32(self.0[i])
-    }
-    unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
-        F32(self.0[i])
-    }
-    fn replace(&mut self, i: usize, val: Self::Element) {
-        assert_lt!(i, N);
-        self.0[i] = F32(val);
-    }
-    unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
-        self.0[i] = F32(val);
-    }
-    fn select(mut self, cond: bool, other: Self) -> Self {
-    	for i in 0..N {
-    		if !cond.0[i] {
-    			self.0[i] = other.0[i];
-    		}
-    	}
+    fn select(mut self, cond: Self, other: Self) -> Self {
+        for i in 0..N {
+            if !cond.0[i] {
+                self.0[i] = other.0[i];
+            }
+        }
         self
     }
 }
 
-impl<const N: usize> BitAnd for F32xN<N> {
-    type Output = Self;
+impl<const N: usize> SimdBool for BoolxN<N> {
     #[inline]
-    fn bitand(mut self, rhs: Self) -> Self {
-    	self &= rhs;
-    	self
+    fn bitmask(self) -> u64 {
+        assert!(N <= 64, "Too many lanes to fit in a bitmask");
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0, |mask, (i, &b)| mask | ((b as u64) << i))
     }
-}
-
-impl<const N: usize> BitAndAssign for F32xN<N> {
     #[inline]
-    fn bitand_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-    		self.0[i] = self.0[i] && rhs.0[i]
-    	}
+    fn and(self) -> bool {
+        self.0.iter().all(|&b| b)
     }
-}
-
-impl<const N: usize> BitOr for BoolxN<N> {
-    type Output = Self;
     #[inline]
-    fn bitor(self, rhs: Self) -> Self {
-        self |= rhs;
-        self
+    fn or(self) -> bool {
+        self.0.iter().any(|&b| b)
     }
-}
-
-impl<const N: usize> BitOrAssign for BoolxN<N> {
     #[inline]
-    fn bitor_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-        	self.0[i] = self.0[i] || rhs.0[i];
-    	}
+    fn xor(self) -> bool {
+        self.0.iter().filter(|&&b| b).count() % 2 != 0
     }
-}
-
-impl<const N: usize> BitXor for BoolxN<N> {
-    type Output = Self;
     #[inline]
-    fn bitxor(mut self, rhs: Self) -> Self {
-        self ^= rhs;
-        self
+    fn all(self) -> bool {
+        self.and()
     }
-}
-
-impl<const N: usize> BitXorAssign for BoolxN<N> {
     #[inline]
-    fn bitxor_assign(&mut self, rhs: Self) {
-    	for i in 0..N {
-        	self.0[i] = self.0[i] != rhs.0[i];
-    	}
+    fn any(self) -> bool {
+        self.or()
     }
-}
-
-impl<const N: usize> Not for BoolxN<N> {
-    type Output = Self;
     #[inline]
-    fn div(mut self) -> Self {
-    	for i in 0..N {
-    		self.0[i] = !self.0[i];
-    	}
-        self
-    }
-}
-
-fn id<T>(&x: &T) -> T { x }
-
-impl<const N: usize> SimdBool for BoolxN<N>{
-    fn bitmask(self) -> u64 {
-    	assert_le(N, 64, "Too many elements to fit in bitmask");
-    	self.0.iter().enumerate().fold(0, |mask, (i, b)| mask | ((b as u64) << i)
+    fn none(self) -> bool {
+        !self.or()
     }
-    fn and(self) -> bool { self.0.iter().all(id) }
-    fn or(self) -> bool { self.0.iter().any(id) }
-    fn xor(self) -> bool { self.0.iter().filter(|&&x|x).count() & 1 != 0 }
-    fn all(self) -> bool { self.0.iter().all(id) }
-    fn any(self) -> bool { self.0.iter().any(id) }
-    fn none(self) -> bool { !self.0.iter().any(id) }
+    #[inline]
     fn if_else<Res: SimdValue<SimdBool = Self>>(
-        self, 
-        if_value: impl FnOnce() -> Res, 
-        else_value: impl FnOnce() -> Res
+        self,
+        if_value: impl FnOnce() -> Res,
+        else_value: impl FnOnce() -> Res,
     ) -> Res {
-    	if_value().select(self, else_value())
+        if_value().select(self, else_value())
     }
+    #[inline]
     fn if_else2<Res: SimdValue<SimdBool = Self>>(
-        self, 
-        if_value: impl FnOnce() -> Res, 
-        else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res), 
-        else_value: impl FnOnce() -> Res
+        self,
+        if_value: impl FnOnce() -> Res,
+        else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+        else_value: impl FnOnce() -> Res,
     ) -> Res {
-    	if_value().select(self, else_if.1().select(else_if.0(), else_value()))
+        let else_value = else_if.1().select(else_if.0(), else_value());
+        if_value().select(self, else_value)
     }
+    #[inline]
     fn if_else3<Res: SimdValue<SimdBool = Self>>(
-        self, 
-        if_value: impl FnOnce() -> Res, 
-        else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res), 
-        else_else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res), 
-        else_value: impl FnOnce() -> Res
+        self,
+        if_value: impl FnOnce() -> Res,
+        else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+        else_else_if: (impl FnOnce() -> Self, impl FnOnce() -> Res),
+        else_value: impl FnOnce() -> Res,
     ) -> Res {
-    	if_value().select(self, else_if.1().select(else_if.0(), else_else_if.1().select(else_else_if.0(), else_value())))
+        let else_value = else_else_if.1().select(else_else_if.0(), else_value());
+        let else_value = else_if.1().select(else_if.0(), else_value);
+        if_value().select(self, else_value)
     }
 }
 
-impl<const N: usize> Field for F32xN<N> {}
+/// Implements a SIMD vector of `N` fast math floats with the scalar type `$s` wrapping `$f`.
+macro_rules! impl_simd_float {
+    ($(#[$attr:meta])* $t:ident, $s:ident, $f:ident) => {
+        $(#[$attr])*
+        #[cfg_attr(feature = "repr_simd", repr(simd))]
+        #[cfg_attr(not(feature = "repr_simd"), repr(align(32)))]
+        #[derive(Copy, Clone)]
+        pub struct $t<const N: usize>([$f; N]);
+
+        // rustc doesn't allow projecting into a `repr(simd)` type, so the lanes are only ever
+        // accessed through these casts to the array they're laid out as. This also rules out
+        // deriving `PartialEq` and `Debug`.
+        impl<const N: usize> $t<N> {
+            #[inline(always)]
+            fn lanes(&self) -> &[$f; N] {
+                unsafe { &*(self as *const Self as *const [$f; N]) }
+            }
 
-impl<const N: usize> SubsetOf<F32xN<N>> for F32xN<N> {
-    fn to_superset(&self) -> Self {
-        *self
-    }
-    fn from_superset_unchecked(element: &Self) -> Self {
-        *element
-    }
-    fn is_in_subset(_element: &Self) -> bool {
-        true
-    }
+            #[inline(always)]
+            fn lanes_mut(&mut self) -> &mut [$f; N] {
+                unsafe { &mut *(self as *mut Self as *mut [$f; N]) }
+            }
+        }
+
+        impl<const N: usize> PartialEq for $t<N> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.lanes() == other.lanes()
+            }
+        }
+
+        impl<const N: usize> Debug for $t<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($t)).field(self.lanes()).finish()
+            }
+        }
+
+        impl<const N: usize> $t<N> {
+            #[inline]
+            pub fn new(x: [$f; N]) -> Self {
+                Self::try_new(x).expect("Fast math operations unsafe with infinity and NaN")
+            }
+
+            #[inline]
+            pub fn splat(x: $f) -> Self {
+                Self::try_splat(x).expect("Fast math operations unsafe with infinity and NaN")
+            }
+
+            #[inline]
+            pub fn try_new(x: [$f; N]) -> Option<Self> {
+                if x.iter().all(|x| x.is_finite()) {
+                    Some(Self(x))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            pub fn try_splat(x: $f) -> Option<Self> {
+                if x.is_finite() {
+                    Some(Self([x; N]))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            pub fn into_inner(self) -> [$f; N] {
+                *self.lanes()
+            }
+
+            /// Applies a scalar operation to every lane.
+            #[inline]
+            fn map(self, f: impl Fn($s) -> $s) -> Self {
+                Self(self.lanes().map(|x| f($s(x)).0))
+            }
+
+            /// Applies a scalar operation to every pair of corresponding lanes.
+            #[inline]
+            fn zip(mut self, rhs: Self, f: impl Fn($s, $s) -> $s) -> Self {
+                for i in 0..N {
+                    self.lanes_mut()[i] = f($s(self.lanes()[i]), $s(rhs.lanes()[i])).0;
+                }
+                self
+            }
+
+            /// Compares every pair of corresponding lanes.
+            #[inline]
+            fn compare(self, rhs: Self, f: impl Fn(&$f, &$f) -> bool) -> BoolxN<N> {
+                let mut out = [false; N];
+                for i in 0..N {
+                    out[i] = f(&self.lanes()[i], &rhs.lanes()[i]);
+                }
+                BoolxN(out)
+            }
+
+            /// Combines all lanes into a single scalar.
+            #[inline]
+            fn fold(self, f: impl Fn($s, $s) -> $s) -> $s {
+                self.lanes()[1..].iter().fold($s(self.lanes()[0]), |acc, &x| f(acc, $s(x)))
+            }
+        }
+
+        impl<const N: usize> From<[$f; N]> for $t<N> {
+            #[inline]
+            fn from(x: [$f; N]) -> Self {
+                Self::new(x)
+            }
+        }
+
+        impl<const N: usize> Display for $t<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (i, x) in self.lanes().iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(x, f)?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        impl<const N: usize> LowerExp for $t<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (i, x) in self.lanes().iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    LowerExp::fmt(x, f)?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        impl<const N: usize> UpperExp for $t<N> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (i, x) in self.lanes().iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    UpperExp::fmt(x, f)?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        impl<const N: usize> Add for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> AddAssign for $t<N> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                for i in 0..N {
+                    self.lanes_mut()[i] = unsafe { fadd_fast(self.lanes()[i], rhs.lanes()[i]) };
+                }
+            }
+        }
+
+        impl<const N: usize> Sub for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: Self) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> SubAssign for $t<N> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                for i in 0..N {
+                    self.lanes_mut()[i] = unsafe { fsub_fast(self.lanes()[i], rhs.lanes()[i]) };
+                }
+            }
+        }
+
+        impl<const N: usize> Mul for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn mul(mut self, rhs: Self) -> Self {
+                self *= rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> MulAssign for $t<N> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                for i in 0..N {
+                    self.lanes_mut()[i] = unsafe { fmul_fast(self.lanes()[i], rhs.lanes()[i]) };
+                }
+            }
+        }
+
+        impl<const N: usize> Div for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, rhs: Self) -> Self {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> DivAssign for $t<N> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                assert!(!rhs.lanes().iter().any(|x| x.is_zero()), "Division by zero");
+                for i in 0..N {
+                    self.lanes_mut()[i] = unsafe { fdiv_fast(self.lanes()[i], rhs.lanes()[i]) };
+                }
+            }
+        }
+
+        impl<const N: usize> Rem for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn rem(mut self, rhs: Self) -> Self {
+                self %= rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> RemAssign for $t<N> {
+            #[inline]
+            fn rem_assign(&mut self, rhs: Self) {
+                assert!(!rhs.lanes().iter().any(|x| x.is_zero()), "Division by zero");
+                for i in 0..N {
+                    self.lanes_mut()[i] = unsafe { frem_fast(self.lanes()[i], rhs.lanes()[i]) };
+                }
+            }
+        }
+
+        impl<const N: usize> Neg for $t<N> {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self([0.0; N]) - self
+            }
+        }
+
+        impl<const N: usize> Zero for $t<N> {
+            #[inline]
+            fn zero() -> Self {
+                Self([0.0; N])
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.lanes().iter().all(|&x| x == 0.0)
+            }
+        }
+
+        impl<const N: usize> One for $t<N> {
+            #[inline]
+            fn one() -> Self {
+                Self([1.0; N])
+            }
+        }
+
+        impl<const N: usize> Num for $t<N> {
+            type FromStrRadixErr = <$f as Num>::FromStrRadixErr;
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                $f::from_str_radix(str, radix).map(Self::splat)
+            }
+        }
+
+        impl<const N: usize> SimdValue for $t<N> {
+            type Element = $s;
+            type SimdBool = BoolxN<N>;
+            #[inline]
+            fn lanes() -> usize {
+                N
+            }
+            #[inline]
+            fn splat(x: $s) -> Self {
+                Self([x.0; N])
+            }
+            #[inline]
+            fn extract(&self, i: usize) -> $s {
+                $s(self.lanes()[i])
+            }
+            #[inline]
+            unsafe fn extract_unchecked(&self, i: usize) -> $s {
+                $s(*self.lanes().get_unchecked(i))
+            }
+            #[inline]
+            fn replace(&mut self, i: usize, val: $s) {
+                self.lanes_mut()[i] = val.0;
+            }
+            #[inline]
+            unsafe fn replace_unchecked(&mut self, i: usize, val: $s) {
+                *self.lanes_mut().get_unchecked_mut(i) = val.0;
+            }
+            #[inline]
+            fn select(mut self, cond: BoolxN<N>, other: Self) -> Self {
+                for i in 0..N {
+                    if !cond.0[i] {
+                        self.lanes_mut()[i] = other.lanes()[i];
+                    }
+                }
+                self
+            }
+        }
+
+        impl<const N: usize> Field for $t<N> {}
+
+        impl<const N: usize> SubsetOf<$t<N>> for $t<N> {
+            #[inline]
+            fn to_superset(&self) -> Self {
+                *self
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &Self) -> Self {
+                *element
+            }
+            #[inline]
+            fn is_in_subset(_element: &Self) -> bool {
+                true
+            }
+        }
+
+        impl<const N: usize> SubsetOf<$t<N>> for f64 {
+            #[inline]
+            fn to_superset(&self) -> $t<N> {
+                $t::splat(*self as $f)
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &$t<N>) -> Self {
+                element.lanes()[0] as f64
+            }
+            #[inline]
+            fn is_in_subset(element: &$t<N>) -> bool {
+                element.lanes().iter().all(|&x| x == element.lanes()[0])
+            }
+        }
+
+        impl<const N: usize> SubsetOf<$t<N>> for f32 {
+            #[inline]
+            fn to_superset(&self) -> $t<N> {
+                $t::splat(*self as $f)
+            }
+            #[inline]
+            fn from_superset_unchecked(element: &$t<N>) -> Self {
+                element.lanes()[0] as f32
+            }
+            #[inline]
+            fn is_in_subset(element: &$t<N>) -> bool {
+                element.lanes().iter().all(|&x| x == element.lanes()[0])
+            }
+        }
+
+        impl<const N: usize> SimdPartialOrd for $t<N> {
+            #[inline]
+            fn simd_gt(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialOrd::gt)
+            }
+            #[inline]
+            fn simd_lt(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialOrd::lt)
+            }
+            #[inline]
+            fn simd_ge(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialOrd::ge)
+            }
+            #[inline]
+            fn simd_le(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialOrd::le)
+            }
+            #[inline]
+            fn simd_eq(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialEq::eq)
+            }
+            #[inline]
+            fn simd_ne(self, other: Self) -> BoolxN<N> {
+                self.compare(other, PartialEq::ne)
+            }
+            #[inline]
+            fn simd_max(self, other: Self) -> Self {
+                self.zip(other, RealField::max)
+            }
+            #[inline]
+            fn simd_min(self, other: Self) -> Self {
+                self.zip(other, RealField::min)
+            }
+            #[inline]
+            fn simd_clamp(mut self, min: Self, max: Self) -> Self {
+                for i in 0..N {
+                    let (lo, hi) = ($s(min.lanes()[i]), $s(max.lanes()[i]));
+                    self.lanes_mut()[i] = $s(self.lanes()[i]).clamp(lo, hi).0;
+                }
+                self
+            }
+            #[inline]
+            fn simd_horizontal_min(self) -> $s {
+                self.fold(RealField::min)
+            }
+            #[inline]
+            fn simd_horizontal_max(self) -> $s {
+                self.fold(RealField::max)
+            }
+        }
+
+        impl<const N: usize> SimdSigned for $t<N> {
+            #[inline]
+            fn simd_abs(&self) -> Self {
+                self.map(|x| Signed::abs(&x))
+            }
+            #[inline]
+            fn simd_abs_sub(&self, other: &Self) -> Self {
+                self.zip(*other, |x, y| Signed::abs_sub(&x, &y))
+            }
+            #[inline]
+            fn simd_signum(&self) -> Self {
+                self.map(|x| Signed::signum(&x))
+            }
+            #[inline]
+            fn is_simd_positive(&self) -> BoolxN<N> {
+                self.compare(Self::zero(), PartialOrd::gt)
+            }
+            #[inline]
+            fn is_simd_negative(&self) -> BoolxN<N> {
+                self.compare(Self::zero(), PartialOrd::lt)
+            }
+        }
+
+        impl<const N: usize> SimdComplexField for $t<N> {
+            type SimdRealField = Self;
+            #[inline]
+            fn from_simd_real(re: Self) -> Self {
+                re
+            }
+            #[inline]
+            fn simd_real(self) -> Self {
+                self
+            }
+            #[inline]
+            fn simd_imaginary(self) -> Self {
+                Self::zero()
+            }
+            #[inline]
+            fn simd_modulus(self) -> Self {
+                self.map(ComplexField::modulus)
+            }
+            #[inline]
+            fn simd_modulus_squared(self) -> Self {
+                self * self
+            }
+            #[inline]
+            fn simd_argument(self) -> Self {
+                self.map(ComplexField::argument)
+            }
+            #[inline]
+            fn simd_norm1(self) -> Self {
+                self.map(ComplexField::norm1)
+            }
+            #[inline]
+            fn simd_scale(self, factor: Self) -> Self {
+                self * factor
+            }
+            #[inline]
+            fn simd_unscale(self, factor: Self) -> Self {
+                self / factor
+            }
+            #[inline]
+            fn simd_floor(self) -> Self {
+                self.map(ComplexField::floor)
+            }
+            #[inline]
+            fn simd_ceil(self) -> Self {
+                self.map(ComplexField::ceil)
+            }
+            #[inline]
+            fn simd_round(self) -> Self {
+                self.map(ComplexField::round)
+            }
+            #[inline]
+            fn simd_trunc(self) -> Self {
+                self.map(ComplexField::trunc)
+            }
+            #[inline]
+            fn simd_fract(self) -> Self {
+                self.map(ComplexField::fract)
+            }
+            #[inline]
+            fn simd_mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+            #[inline]
+            fn simd_abs(self) -> Self {
+                self.map(ComplexField::abs)
+            }
+            #[inline]
+            fn simd_hypot(self, other: Self) -> Self {
+                self.zip(other, ComplexField::hypot)
+            }
+            #[inline]
+            fn simd_recip(self) -> Self {
+                self.map(ComplexField::recip)
+            }
+            #[inline]
+            fn simd_conjugate(self) -> Self {
+                self
+            }
+            #[inline]
+            fn simd_sin(self) -> Self {
+                self.map(ComplexField::sin)
+            }
+            #[inline]
+            fn simd_cos(self) -> Self {
+                self.map(ComplexField::cos)
+            }
+            #[inline]
+            fn simd_sin_cos(self) -> (Self, Self) {
+                (self.simd_sin(), self.simd_cos())
+            }
+            #[inline]
+            fn simd_tan(self) -> Self {
+                self.map(ComplexField::tan)
+            }
+            #[inline]
+            fn simd_asin(self) -> Self {
+                self.map(ComplexField::asin)
+            }
+            #[inline]
+            fn simd_acos(self) -> Self {
+                self.map(ComplexField::acos)
+            }
+            #[inline]
+            fn simd_atan(self) -> Self {
+                self.map(ComplexField::atan)
+            }
+            #[inline]
+            fn simd_sinh(self) -> Self {
+                self.map(ComplexField::sinh)
+            }
+            #[inline]
+            fn simd_cosh(self) -> Self {
+                self.map(ComplexField::cosh)
+            }
+            #[inline]
+            fn simd_tanh(self) -> Self {
+                self.map(ComplexField::tanh)
+            }
+            #[inline]
+            fn simd_asinh(self) -> Self {
+                self.map(ComplexField::asinh)
+            }
+            #[inline]
+            fn simd_acosh(self) -> Self {
+                self.map(ComplexField::acosh)
+            }
+            #[inline]
+            fn simd_atanh(self) -> Self {
+                self.map(ComplexField::atanh)
+            }
+            #[inline]
+            fn simd_log(self, base: Self) -> Self {
+                self.zip(base, ComplexField::log)
+            }
+            #[inline]
+            fn simd_log2(self) -> Self {
+                self.map(ComplexField::log2)
+            }
+            #[inline]
+            fn simd_log10(self) -> Self {
+                self.map(ComplexField::log10)
+            }
+            #[inline]
+            fn simd_ln(self) -> Self {
+                self.map(ComplexField::ln)
+            }
+            #[inline]
+            fn simd_ln_1p(self) -> Self {
+                self.map(ComplexField::ln_1p)
+            }
+            #[inline]
+            fn simd_sqrt(self) -> Self {
+                self.map(ComplexField::sqrt)
+            }
+            #[inline]
+            fn simd_exp(self) -> Self {
+                self.map(ComplexField::exp)
+            }
+            #[inline]
+            fn simd_exp2(self) -> Self {
+                self.map(ComplexField::exp2)
+            }
+            #[inline]
+            fn simd_exp_m1(self) -> Self {
+                self.map(ComplexField::exp_m1)
+            }
+            #[inline]
+            fn simd_powi(self, n: i32) -> Self {
+                self.map(|x| x.powi(n))
+            }
+            #[inline]
+            fn simd_powf(self, n: Self) -> Self {
+                self.zip(n, ComplexField::powf)
+            }
+            #[inline]
+            fn simd_powc(self, n: Self) -> Self {
+                self.zip(n, ComplexField::powc)
+            }
+            #[inline]
+            fn simd_cbrt(self) -> Self {
+                self.map(ComplexField::cbrt)
+            }
+            #[inline]
+            fn simd_horizontal_sum(self) -> $s {
+                self.fold(Add::add)
+            }
+            #[inline]
+            fn simd_horizontal_product(self) -> $s {
+                self.fold(Mul::mul)
+            }
+        }
+
+        impl<const N: usize> SimdRealField for $t<N> {
+            #[inline]
+            fn simd_copysign(self, sign: Self) -> Self {
+                self.zip(sign, RealField::copysign)
+            }
+            #[inline]
+            fn simd_atan2(self, other: Self) -> Self {
+                self.zip(other, RealField::atan2)
+            }
+            #[inline]
+            fn simd_default_epsilon() -> Self {
+                SimdValue::splat($s::default_epsilon())
+            }
+            #[inline]
+            fn simd_pi() -> Self {
+                SimdValue::splat($s::pi())
+            }
+            #[inline]
+            fn simd_two_pi() -> Self {
+                SimdValue::splat($s::two_pi())
+            }
+            #[inline]
+            fn simd_frac_pi_2() -> Self {
+                SimdValue::splat($s::frac_pi_2())
+            }
+            #[inline]
+            fn simd_frac_pi_3() -> Self {
+                SimdValue::splat($s::frac_pi_3())
+            }
+            #[inline]
+            fn simd_frac_pi_4() -> Self {
+                SimdValue::splat($s::frac_pi_4())
+            }
+            #[inline]
+            fn simd_frac_pi_6() -> Self {
+                SimdValue::splat($s::frac_pi_6())
+            }
+            #[inline]
+            fn simd_frac_pi_8() -> Self {
+                SimdValue::splat($s::frac_pi_8())
+            }
+            #[inline]
+            fn simd_frac_1_pi() -> Self {
+                SimdValue::splat($s::frac_1_pi())
+            }
+            #[inline]
+            fn simd_frac_2_pi() -> Self {
+                SimdValue::splat($s::frac_2_pi())
+            }
+            #[inline]
+            fn simd_frac_2_sqrt_pi() -> Self {
+                SimdValue::splat($s::frac_2_sqrt_pi())
+            }
+            #[inline]
+            fn simd_e() -> Self {
+                SimdValue::splat($s::e())
+            }
+            #[inline]
+            fn simd_log2_e() -> Self {
+                SimdValue::splat($s::log2_e())
+            }
+            #[inline]
+            fn simd_log10_e() -> Self {
+                SimdValue::splat($s::log10_e())
+            }
+            #[inline]
+            fn simd_ln_2() -> Self {
+                SimdValue::splat($s::ln_2())
+            }
+            #[inline]
+            fn simd_ln_10() -> Self {
+                SimdValue::splat($s::ln_10())
+            }
+        }
+
+        impl<const N: usize> AbsDiffEq for $t<N> {
+            type Epsilon = $s;
+            fn default_epsilon() -> $s {
+                $s::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: $s) -> bool {
+                (0..N).all(|i| self.extract(i).abs_diff_eq(&other.extract(i), epsilon))
+            }
+        }
+
+        impl<const N: usize> RelativeEq for $t<N> {
+            fn default_max_relative() -> $s {
+                $s::default_max_relative()
+            }
+            fn relative_eq(&self, other: &Self, epsilon: $s, max_relative: $s) -> bool {
+                (0..N).all(|i| {
+                    self.extract(i)
+                        .relative_eq(&other.extract(i), epsilon, max_relative)
+                })
+            }
+        }
+
+        impl<const N: usize> UlpsEq for $t<N> {
+            fn default_max_ulps() -> u32 {
+                $s::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: $s, max_ulps: u32) -> bool {
+                (0..N).all(|i| self.extract(i).ulps_eq(&other.extract(i), epsilon, max_ulps))
+            }
+        }
+    };
 }
-impl<const N: usize> SubsetOf<F32xN<N>> for f64 {
-    fn to_superset(&self) -> F32xN<N> {
-        assert!(self.is_finite(), "Fast math operations unsafe with infinity and NaN");
-        F32xN([*self as f32; N])
-    }
-    fn from_superset_unchecked(element: &Self) -> Self {
-        *element
-    }
-    fn is_in_subset(element: &F32xN<N>) -> bool {
-        element.0.iter()
+
+impl_simd_float!(
+    /// A vector of `N` fast math `f32`s.
+    F32xN, F32, f32
+);
+impl_simd_float!(
+    /// A vector of `N` fast math `f64`s.
+    F64xN, F64, f64
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::{Point, Sphere};
+    use crate::Outer;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = F32xN::new([1.0, 2.0, 3.0, 4.0]);
+        let b = F32xN::new([4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(a + b, F32xN::splat(5.0));
+        assert_eq!(a * b, F32xN::new([4.0, 6.0, 6.0, 4.0]));
+        assert_eq!(-a, F32xN::new([-1.0, -2.0, -3.0, -4.0]));
+        assert_eq!(a.simd_gt(b), BoolxN::new([false, false, true, true]));
+        assert_eq!(a.simd_horizontal_sum(), F32::new(10.0));
+        assert_eq!(b.simd_sqrt().extract(0), F32::new(2.0));
+    }
+
+    #[test]
+    fn test_lanes() {
+        let v1: [Point<F64>; 4] = [
+            Point::new([F64::new(1.0), F64::new(2.0), F64::new(4.0)]),
+            Point::new([F64::new(0.0), F64::new(3.0), F64::new(0.0)]),
+            Point::new([F64::new(3.0), F64::new(4.0), F64::new(5.0)]),
+            Point::new([F64::new(-1.0), F64::new(0.5), F64::new(2.0)]),
+        ];
+        let v2: [Point<F64>; 4] = [v1[3], v1[2], v1[1], v1[0]];
+
+        let p1 = Point::<F64xN<4>>::from_lanes(v1);
+        let p2 = Point::<F64xN<4>>::from_lanes(v2);
+        assert_eq!(p1.to_lanes(), v1);
+
+        let distances = p1.distance(p2);
+        let pairs = p1.outer(p2).to_lanes::<4>();
+        for i in 0..4 {
+            assert_abs_diff_eq!(distances.extract(i), v1[i].distance(v2[i]));
+            assert_eq!(pairs[i], v1[i].outer(v2[i]));
+        }
+
+        let spheres: [Sphere<F32>; 4] = core::array::from_fn(|i| {
+            let c: Point<F32> = Point::new([F32::new(i as f32), F32::new(1.0), F32::new(2.0)]);
+            c.into_sphere(F32::new(1.0 + i as f32)).undual()
+        });
+        let centers = Sphere::<F32xN<4>>::from_lanes(spheres).center().to_lanes::<4>();
+        for i in 0..4 {
+            assert_abs_diff_eq!(centers[i], spheres[i].center(), epsilon = F32::new(1e-4));
+        }
     }
-}
\ No newline at end of file
+}
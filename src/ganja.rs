@@ -0,0 +1,271 @@
+//! Conversion to and from the coefficients used by [ganja.js](https://github.com/enkimute/ganja.js),
+//! for visualizing results.
+//!
+//! ganja.js's `Algebra(4,1)` names its basis vectors `e1` to `e5`, where `e4` squares to 1 and `e5`
+//! to -1, so they stand for `ep` and `en`. Its 32 blades are ordered by grade and then
+//! lexicographically, which is also the order of the general multivector used internally, so the
+//! coefficients carry over without any reordering or sign changes. Points use the same `no` and
+//! `ni` as ganja.js's conformal examples, so objects are drawn where they belong.
+
+use core::fmt::{Display, Write};
+use core::str::FromStr;
+
+use simba::scalar::RealField;
+
+use crate::parse::parse_blades;
+use crate::r410::write_sum;
+use crate::{Multivec, R410};
+
+/// The names ganja.js gives to the blades of `Algebra(4,1)`, in its coefficient order.
+pub const BASIS: [&str; 32] = [
+    "1", "e1", "e2", "e3", "e4", "e5", "e12", "e13", "e14", "e15", "e23", "e24", "e25", "e34",
+    "e35", "e45", "e123", "e124", "e125", "e134", "e135", "e145", "e234", "e235", "e245", "e345",
+    "e1234", "e1235", "e1245", "e1345", "e2345", "e12345",
+];
+
+/// Conversion of objects to and from ganja.js `Algebra(4,1)` coefficients.
+pub trait Ganja: Multivec + Copy {
+    /// The coefficients of the object in the order of [`BASIS`].
+    #[inline]
+    fn to_ganja(self) -> [Self::Element; 32] {
+        self.into_mv().into_array()
+    }
+
+    /// Reads an object from coefficients in the order of [`BASIS`], or `None` if the object can't
+    /// hold them all.
+    ///
+    /// Results computed in ganja.js carry round-off, so the coefficients only have to match the
+    /// object to within the square root of the machine epsilon, relative to the largest of them.
+    /// The `e4` and `e5` halves of a multiple of infinity are combined as `Space::join` does.
+    #[inline]
+    fn from_ganja(coefficients: [Self::Element; 32]) -> Option<Self>
+    where
+        Self::Element: RealField,
+    {
+        from_mv_within(R410::from_array(coefficients))
+    }
+
+    /// Writes the object as a sum of ganja.js blades, e.g. `1e1 + 2e2 - 0.5e45`, which can be
+    /// pasted into code run through `Algebra(4,1)`.
+    fn to_ganja_string(self) -> String
+    where
+        Self::Element: RealField + Display,
+    {
+        let mut out = String::new();
+        write_sum(&mut out, &self.into_mv(), &BASIS, |c| c.to_string()).unwrap();
+        out
+    }
+
+    /// Parses a sum of ganja.js blades as written by [`to_ganja_string`](Ganja::to_ganja_string).
+    /// Terms may appear in any order, and a missing coefficient counts as 1.
+    fn parse_ganja(s: &str) -> Option<Self>
+    where
+        Self::Element: RealField + FromStr,
+    {
+        Self::from_ganja(parse_blades(s, &BASIS).ok()?.into_array())
+    }
+}
+
+impl<X: Multivec + Copy> Ganja for X {}
+
+/// Converts `v` into `X` if that changes no component by more than the relative tolerance
+/// described in [`Ganja::from_ganja`].
+fn from_mv_within<T, X>(v: R410<T>) -> Option<X>
+where
+    T: RealField + Copy,
+    X: Multivec<Element = T> + Copy,
+{
+    let coefficients = v.into_array();
+    let scale = coefficients.iter().fold(T::zero(), |m, &c| m.max(c.abs()));
+    let tol = T::default_epsilon().sqrt() * scale;
+    let x = X::from_mv(v);
+    let error = (x.into_mv() - v).into_array();
+    if error.iter().all(|c| c.abs() <= tol) {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// A list of objects to draw with ganja.js, written out as a standalone HTML page.
+///
+/// The page loads ganja.js from unpkg and needs no other files.
+#[derive(Clone, Debug, Default)]
+pub struct Scene {
+    items: Vec<String>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an object drawn in the color `0xRRGGBB`, with an optional label.
+    pub fn add<X: Ganja>(&mut self, object: X, color: u32, label: Option<&str>) -> &mut Self
+    where
+        X::Element: Into<f64>,
+    {
+        let mut item = format!("0x{:06x}, new CGA([", color);
+        for (i, c) in object.to_ganja().iter().enumerate() {
+            let sep = if i > 0 { ", " } else { "" };
+            write!(item, "{}{:?}", sep, (*c).into()).unwrap();
+        }
+        item.push_str("])");
+        if let Some(label) = label {
+            item.push_str(", ");
+            write_js_string(&mut item, label);
+        }
+        self.items.push(item);
+        self
+    }
+
+    /// Writes the page that draws every object added so far.
+    pub fn to_html(&self) -> String {
+        let mut out = String::from(concat!(
+            "<!DOCTYPE html>\n",
+            "<html>\n",
+            "<head>\n",
+            "<meta charset=\"utf-8\">\n",
+            "<script src=\"https://unpkg.com/ganja.js\"></script>\n",
+            "</head>\n",
+            "<body>\n",
+            "<script>\n",
+            "var CGA = Algebra(4, 1);\n",
+            "document.body.appendChild(CGA.graph([\n",
+        ));
+        for item in &self.items {
+            out.push_str("  ");
+            out.push_str(item);
+            out.push_str(",\n");
+        }
+        out.push_str(concat!(
+            "], {conformal: true, gl: true, grid: true}));\n",
+            "</script>\n",
+            "</body>\n",
+            "</html>\n",
+        ));
+        out
+    }
+}
+
+/// Writes a JavaScript string literal that is also safe to embed in a `<script>` element.
+fn write_js_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '<' => out.push_str("\\x3c"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                write!(out, "\\u{{{:x}}}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod test {
+    use num_traits::zero;
+
+    use super::*;
+    use crate::d3::flat::{FPoint, Line};
+    use crate::d3::free::Vector;
+    use crate::d3::round::{Pair, Point};
+    use crate::d3::transform::{Motor, Rotor};
+    use crate::r410::BLADE_NAMES;
+    use crate::{Hyperbolic, Normalize, Outer};
+
+    #[test]
+    fn test_basis() {
+        for (ganja, ours) in BASIS.iter().zip(BLADE_NAMES.iter()) {
+            let renamed = ours.replace('s', "1").replace('p', "4").replace('n', "5");
+            assert_eq!(*ganja, renamed);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let p1 = Point::<f64>::new([1.0, 2.0, 2.0]);
+        let p2 = Point::new([0.0, -1.0, 3.0]);
+        let c = p1.to_ganja();
+        assert_eq!(c[1..6], [1.0, 2.0, 2.0, 4.0, 5.0]);
+        assert_eq!(Point::from_ganja(c), Some(p1));
+
+        let l = p1.outer(p2).extend();
+        assert_eq!(Line::from_ganja(l.to_ganja()), Some(l));
+        let r = Rotor::from_axis_angle(Vector::new(0.0, 1.0, 0.0), 0.5);
+        let m = Motor::new(r, Vector::new(1.0, 2.0, 3.0));
+        assert_eq!(Motor::from_ganja(m.to_ganja()).map(Ganja::to_ganja), Some(m.to_ganja()));
+
+        let h = Point::<f64, Hyperbolic>::new([1.0, 2.0, 2.0]).outer(Point::new([0.0, 1.0, 0.0]));
+        assert_eq!(Pair::from_ganja(h.to_ganja()), Some(h));
+    }
+
+    #[test]
+    fn test_rejects() {
+        // A pair component doesn't fit in a point.
+        let mut c = Point::<f64>::new([1.0, 2.0, 2.0]).to_ganja();
+        c[6] = 1.0;
+        assert_eq!(Point::<f64>::from_ganja(c), None);
+
+        // The halves of e12∞ must agree in Euclidean space.
+        let mut c = [0.0; 32];
+        c[17] = 1.0;
+        c[18] = 2.0;
+        assert!(Line::<f64>::from_ganja(c).is_none());
+        c[18] = 1.0;
+        assert!(Line::<f64>::from_ganja(c).is_some());
+        assert!(Line::<f64, Hyperbolic>::from_ganja(c).is_none());
+    }
+
+    #[test]
+    fn test_round_off() {
+        // ganja.js results may have halves of infinity that differ in the last bit.
+        let l = Point::<f64>::new([1.0, 2.0, 2.0]).outer(Point::new([0.0, -1.0, 3.0])).extend();
+        let mut c = l.to_ganja();
+        assert_eq!(BASIS[18], "e125");
+        assert!(c[18] != 0.0);
+        c[18] = f64::from_bits(c[18].to_bits() + 1);
+        let imported = Line::from_ganja(c).unwrap();
+        assert!(imported.eq_projective(l, 1e-12));
+
+        let mut c = Point::<f32>::new([1.0, -2.0, 0.5]).into_flat().to_ganja();
+        c[9] = f32::from_bits(c[9].to_bits() + 1);
+        assert!(FPoint::<f32>::from_ganja(c).is_some());
+    }
+
+    #[test]
+    fn test_string() {
+        let p = Point::<f64>::new([1.0, -2.0, 2.0]);
+        assert_eq!(p.to_ganja_string(), "1e1 - 2e2 + 2e3 + 4e4 + 5e5");
+        assert_eq!(Point::parse_ganja("1e1 - 2e2 + 2e3 + 4e4 + 5e5"), Some(p));
+        assert_eq!(Point::parse_ganja("5e5+4e4+2e3-2e2+e1"), Some(p));
+        assert_eq!(Motor::<f64>::from(Rotor::identity()).to_ganja_string(), "1");
+        assert_eq!(Point::<f64>::from_mv(zero()).to_ganja_string(), "0");
+        assert_eq!(Point::<f64>::parse_ganja("0"), Some(Point::from_mv(zero())));
+
+        assert_eq!(Point::<f64>::parse_ganja(""), None);
+        assert_eq!(Point::<f64>::parse_ganja("1e6"), None);
+        assert_eq!(Point::<f64>::parse_ganja("xe1"), None);
+        assert_eq!(Point::<f64>::parse_ganja("1e12"), None);
+    }
+
+    #[test]
+    fn test_html() {
+        let p = Point::<f64>::new([1.0, 0.0, 0.0]);
+        let html = Scene::new()
+            .add(p, 0xff0000, Some("</script> \"p\""))
+            .add(p.into_sphere(0.5), 0x00ff00, None)
+            .to_html();
+        assert!(html.contains("var CGA = Algebra(4, 1);"));
+        assert!(html.contains("0xff0000, new CGA([0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,"));
+        assert!(html.contains(r#""\x3c/script> \"p\"""#));
+        assert!(html.contains("0x00ff00, new CGA(["));
+        assert!(!html.contains("</script> "));
+    }
+}
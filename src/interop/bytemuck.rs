@@ -0,0 +1,126 @@
+//! Every object is `#[repr(C)]` and made up only of scalars and zero-sized `PhantomData`, so the
+//! `f32` and `f64` instantiations can be cast to and from bytes for upload to the GPU. The order
+//! of the components is given in each type's documentation and can be mirrored field by field in
+//! a WGSL or GLSL struct:
+//!
+//! ```wgsl
+//! struct Motor { s: f32, e12: f32, e13: f32, e23: f32, e1i: f32, e2i: f32, e3i: f32, e123i: f32 }
+//! ```
+//!
+//! Components ending in `i` hold the coefficient of a blade containing infinity, which the
+//! object's `Space` splits into the `p` and `n` halves. In Euclidean space these are equal.
+//!
+//! Members of a WGSL struct are aligned to their own size, so `f32` components pack tightly.
+//! Under GLSL's `std140` rules, however, a struct is padded to a multiple of 16 bytes when it is
+//! an array element, so e.g. a `Point<f32>` should be padded to 8 floats in a uniform buffer.
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::d3::direction::DVector;
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::free::{Bivector, Trivector, Vector};
+use crate::d3::round::{Circle, Pair, Point, Sphere};
+use crate::d3::transform::{Dilator, DualQuaternion, Motor, Rotor, Translator};
+use crate::Space;
+
+macro_rules! impl_pod {
+    ($($t:ident $(<$s:ident>)?),*) => {$(
+        // SAFETY: the type is `#[repr(C)]` and holds only scalars of a single type alongside
+        // zero-sized markers, so it has no padding and every bit pattern is valid.
+        unsafe impl<$($s: Space + 'static)?> Zeroable for $t<f32 $(, $s)?> {}
+        unsafe impl<$($s: Space + 'static)?> Pod for $t<f32 $(, $s)?> {}
+        unsafe impl<$($s: Space + 'static)?> Zeroable for $t<f64 $(, $s)?> {}
+        unsafe impl<$($s: Space + 'static)?> Pod for $t<f64 $(, $s)?> {}
+    )*};
+}
+
+impl_pod!(
+    Point<S>, Pair<S>, Circle<S>, Sphere<S>,
+    FPoint<S>, Line<S>, Plane<S>,
+    DLine<S>, DPlane<S>, DSphere<S>, DVector<S>,
+    Vector, Bivector, Trivector,
+    Rotor, Translator<S>, Motor, Dilator, DualQuaternion
+);
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, size_of};
+
+    use ::bytemuck::{cast, cast_slice, Zeroable};
+
+    use super::*;
+    use crate::{Hyperbolic, Multivec, Outer};
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(size_of::<Point<f32>>(), 5 * 4);
+        assert_eq!(size_of::<Point<f64, Hyperbolic>>(), 5 * 8);
+        assert_eq!(size_of::<Pair<f32>>(), 10 * 4);
+        assert_eq!(size_of::<Circle<f32>>(), 10 * 4);
+        assert_eq!(size_of::<Sphere<f32>>(), 5 * 4);
+        assert_eq!(size_of::<FPoint<f32>>(), 4 * 4);
+        assert_eq!(size_of::<Line<f32>>(), 6 * 4);
+        assert_eq!(size_of::<Plane<f32>>(), 4 * 4);
+        assert_eq!(size_of::<DLine<f32>>(), 6 * 4);
+        assert_eq!(size_of::<DPlane<f32>>(), 4 * 4);
+        assert_eq!(size_of::<DSphere<f32>>(), 5 * 4);
+        assert_eq!(size_of::<DVector<f32>>(), 3 * 4);
+        assert_eq!(size_of::<Vector<f32>>(), 3 * 4);
+        assert_eq!(size_of::<Bivector<f32>>(), 3 * 4);
+        assert_eq!(size_of::<Trivector<f32>>(), 4);
+        assert_eq!(size_of::<Rotor<f32>>(), 4 * 4);
+        assert_eq!(size_of::<Translator<f32>>(), 4 * 4);
+        assert_eq!(size_of::<Motor<f64>>(), 8 * 8);
+        assert_eq!(size_of::<Dilator<f32>>(), 2 * 4);
+        assert_eq!(size_of::<DualQuaternion<f32>>(), 8 * 4);
+        assert_eq!(align_of::<Motor<f32>>(), align_of::<f32>());
+    }
+
+    #[test]
+    fn test_order() {
+        let p = Point::<f32>::new([1.0, 2.0, 2.0]);
+        assert_eq!(cast::<_, [f32; 5]>(p), [1.0, 2.0, 2.0, 4.0, 5.0]);
+
+        let s = p.into_sphere(1.0);
+        let mv = s.into_mv();
+        assert_eq!(cast::<_, [f32; 5]>(s), [mv.e1, mv.e2, mv.e3, mv.ep, mv.en]);
+
+        let l = p.outer(Point::new([0.0, 0.0, 0.0])).extend();
+        let mv = l.into_mv();
+        let [e12i, e13i, e23i, e1pn, e2pn, e3pn]: [f32; 6] = cast(l);
+        assert_eq!([e12i, e13i, e23i], [mv.e12p, mv.e13p, mv.e23p]);
+        assert_eq!([e1pn, e2pn, e3pn], [mv.e1pn, mv.e2pn, mv.e3pn]);
+
+        let r = Rotor::from_axis_angle(Vector::new(0.0, 0.0, 1.0), 1.0);
+        let m = Motor::new(r, Vector::new(2.0, 4.0, 6.0));
+        let mv = m.into_mv();
+        assert_eq!(
+            cast::<_, [f32; 8]>(m),
+            [mv.s, mv.e12, mv.e13, mv.e23, mv.e1p, mv.e2p, mv.e3p, mv.e123p]
+        );
+
+        let t = Vector::<f32>::new(2.0, 4.0, 6.0).into_translator::<Hyperbolic>();
+        assert_eq!(cast::<_, [f32; 4]>(t), [-1.0, -2.0, -3.0, 1.0]);
+
+        let dq = DualQuaternion::from(m);
+        let flat: [f32; 8] = cast(dq);
+        assert_eq!(flat[..4], dq.real);
+        assert_eq!(flat[4..], dq.dual);
+    }
+
+    #[test]
+    fn test_slices() {
+        let motors = [
+            Motor::<f32>::new(Rotor::identity(), Vector::new(1.0, 0.0, 0.0)),
+            Motor::zeroed(),
+        ];
+        let floats: &[f32] = cast_slice(&motors);
+        assert_eq!(floats.len(), 16);
+        assert_eq!(floats[0], 1.0);
+        assert!(floats[8..].iter().all(|&x| x == 0.0));
+
+        let back: &[Motor<f32>] = cast_slice(floats);
+        assert_eq!(back[0].into_mv(), motors[0].into_mv());
+    }
+}
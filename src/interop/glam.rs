@@ -0,0 +1,133 @@
+use ::glam::{Affine3A, DAffine3, DMat4, DQuat, DVec3, Mat4, Quat, Vec3, Vec3A};
+
+use crate::d3::free::Vector;
+use crate::d3::round::Point;
+use crate::d3::transform::{Motor, Rotor};
+use crate::Space;
+
+macro_rules! impl_glam {
+    ($t:ty, $vec:ident, $quat:ident, $affine:ident, $mat:ident, $from_affine:ident) => {
+        impl From<$vec> for Vector<$t> {
+            #[inline]
+            fn from(v: $vec) -> Self {
+                Vector::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<Vector<$t>> for $vec {
+            #[inline]
+            fn from(v: Vector<$t>) -> Self {
+                $vec::new(v.e1, v.e2, v.e3)
+            }
+        }
+
+        impl<S: Space> From<$vec> for Point<$t, S> {
+            #[inline]
+            fn from(v: $vec) -> Self {
+                Point::new(Vector::from(v))
+            }
+        }
+
+        impl<S: Space> From<Point<$t, S>> for $vec {
+            #[inline]
+            fn from(p: Point<$t, S>) -> Self {
+                p.position().into()
+            }
+        }
+
+        impl From<$quat> for Rotor<$t> {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                let [x, y, z, w] = q.to_array();
+                Rotor::from_quaternion([w, x, y, z])
+            }
+        }
+
+        impl From<Rotor<$t>> for $quat {
+            /// Normalizes the rotor, since glam expects unit quaternions.
+            #[inline]
+            fn from(r: Rotor<$t>) -> Self {
+                let [w, x, y, z] = r.normalize().to_quaternion();
+                $quat::from_xyzw(x, y, z, w)
+            }
+        }
+
+        impl From<Motor<$t>> for $affine {
+            #[inline]
+            fn from(m: Motor<$t>) -> Self {
+                $affine::from_rotation_translation(m.rotor().into(), m.translation().into())
+            }
+        }
+
+        impl Motor<$t> {
+            #[doc = concat!("Converts a `", stringify!($affine), "` into a motor.")]
+            /// Returns `None` if the transform isn't rigid, as in [`Motor::from_matrix4`].
+            #[inline]
+            pub fn $from_affine(a: $affine) -> Option<Self> {
+                Self::from_matrix4($mat::from(a).transpose().to_cols_array_2d())
+            }
+        }
+    };
+}
+
+impl_glam!(f32, Vec3, Quat, Affine3A, Mat4, from_affine3a);
+impl_glam!(f64, DVec3, DQuat, DAffine3, DMat4, from_daffine3);
+
+impl From<Vec3A> for Vector<f32> {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        Vector::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector<f32>> for Vec3A {
+    #[inline]
+    fn from(v: Vector<f32>) -> Self {
+        Vec3A::new(v.e1, v.e2, v.e3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::Transform;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_f32() {
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        assert_eq!(Vec3::from(Vector::from(v)), v);
+        assert_eq!(Vec3A::from(Vector::from(Vec3A::from(v))), Vec3A::from(v));
+        assert_eq!(Vec3::from(Point::<f32>::from(v)), v);
+
+        let q = Quat::from_axis_angle(Vec3::new(1.0, 2.0, -1.0).normalize(), 0.8);
+        let r = Rotor::from(q);
+        assert!(Vec3::from(r.transform(Vector::from(v))).abs_diff_eq(q * v, 1e-6));
+        assert!(Quat::from(r).abs_diff_eq(q, 1e-6));
+
+        let a = Affine3A::from_rotation_translation(q, Vec3::new(3.0, -1.0, 0.25));
+        let m = Motor::from_affine3a(a).unwrap();
+        let moved = Vec3::from(m.transform(Point::from(v)));
+        assert!(moved.abs_diff_eq(a.transform_point3(v), 1e-5));
+        assert!(Affine3A::from(m).abs_diff_eq(a, 1e-5));
+        assert!(Motor::from_affine3a(Affine3A::from_scale(Vec3::splat(2.0))).is_none());
+    }
+
+    #[test]
+    fn test_f64() {
+        let v = DVec3::new(1.0, -2.0, 0.5);
+        assert_eq!(DVec3::from(Point::<f64, crate::Hyperbolic>::from(v)), v);
+
+        let q = DQuat::from_rotation_y(-1.3);
+        let a = DAffine3::from_rotation_translation(q, DVec3::new(3.0, -1.0, 0.25));
+        let m = Motor::from_daffine3(a).unwrap();
+        let moved = DVec3::from(m.transform(Point::from(v)));
+        assert!(moved.abs_diff_eq(a.transform_point3(v), 1e-12));
+        assert!(DAffine3::from(m).abs_diff_eq(a, 1e-12));
+        let [x, y, z, w] = DQuat::from(Rotor::from(q)).to_array();
+        assert_relative_eq!(w, q.w, epsilon = 1e-12);
+        assert_relative_eq!(x, q.x, epsilon = 1e-12);
+        assert_relative_eq!(y, q.y, epsilon = 1e-12);
+        assert_relative_eq!(z, q.z, epsilon = 1e-12);
+    }
+}
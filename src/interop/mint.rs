@@ -0,0 +1,115 @@
+use ::mint::{ColumnMatrix4, Point3, Quaternion, RowMatrix4, Vector3};
+
+use crate::d3::free::Vector;
+use crate::d3::round::Point;
+use crate::d3::transform::{Motor, Rotor};
+use crate::{Field, Space};
+
+impl<T: Field + Copy> From<Vector3<T>> for Vector<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Vector::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Field + Copy> From<Vector<T>> for Vector3<T> {
+    #[inline]
+    fn from(v: Vector<T>) -> Self {
+        Vector3 {
+            x: v.e1,
+            y: v.e2,
+            z: v.e3,
+        }
+    }
+}
+
+impl<T: Field + Copy, S: Space> From<Point3<T>> for Point<T, S> {
+    #[inline]
+    fn from(p: Point3<T>) -> Self {
+        Point::new(Vector::new(p.x, p.y, p.z))
+    }
+}
+
+impl<T: Field + Copy, S: Space> From<Point<T, S>> for Point3<T> {
+    #[inline]
+    fn from(p: Point<T, S>) -> Self {
+        let v = p.position();
+        Point3 {
+            x: v.e1,
+            y: v.e2,
+            z: v.e3,
+        }
+    }
+}
+
+impl<T: Field + Copy> From<Quaternion<T>> for Rotor<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Self {
+        Rotor::from_quaternion([q.s, q.v.x, q.v.y, q.v.z])
+    }
+}
+
+impl<T: Field + Copy> From<Rotor<T>> for Quaternion<T> {
+    #[inline]
+    fn from(r: Rotor<T>) -> Self {
+        let [s, x, y, z] = r.to_quaternion();
+        Quaternion {
+            s,
+            v: Vector3 { x, y, z },
+        }
+    }
+}
+
+impl<T: Field + Copy> From<Motor<T>> for RowMatrix4<T> {
+    #[inline]
+    fn from(m: Motor<T>) -> Self {
+        m.to_matrix4().into()
+    }
+}
+
+impl<T: Field + Copy> From<Motor<T>> for ColumnMatrix4<T> {
+    #[inline]
+    fn from(m: Motor<T>) -> Self {
+        RowMatrix4::from(m).into()
+    }
+}
+
+impl<T: simba::scalar::RealField + Copy> Motor<T> {
+    /// Converts a mint matrix into a motor.
+    /// Returns `None` if the transform isn't rigid, as in [`Motor::from_matrix4`].
+    #[inline]
+    pub fn from_mint(m: impl Into<RowMatrix4<T>>) -> Option<Self> {
+        Self::from_matrix4(m.into().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::Transform;
+    use crate::Multivec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_conversions() {
+        let v = Vector3 { x: 1.0, y: -2.0, z: 0.5 };
+        assert_eq!(Vector3::from(Vector::from(v)), v);
+        let p = Point3 { x: 1.0, y: -2.0, z: 0.5 };
+        assert_eq!(Point3::from(Point::<f64>::from(p)), p);
+
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        let q = Quaternion::from(r);
+        assert_eq!(Rotor::from(q).into_mv(), r.into_mv());
+
+        let m = Motor::new(r, Vector::new(3.0, -1.0, 0.25));
+        let cols = ColumnMatrix4::from(m);
+        assert_eq!(cols.w.w, 1.0);
+        assert_relative_eq!(cols.w.x, 3.0, epsilon = 1e-12);
+        let back = Motor::from_mint(cols).unwrap();
+        let x = Point::<f64>::new([0.5, 1.0, -2.0]);
+        let (a, b) = (back.transform(x).position(), m.transform(x).position());
+        assert_relative_eq!(a.x(), b.x(), epsilon = 1e-12);
+        assert_relative_eq!(a.y(), b.y(), epsilon = 1e-12);
+        assert_relative_eq!(a.z(), b.z(), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,16 @@
+//! Conversions to and from the types of other crates, each behind a feature of the same name.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "glam")]
+mod glam;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
@@ -0,0 +1,198 @@
+use ::nalgebra::{
+    Isometry3, Point3, Quaternion, Rotation3, Similarity3, Translation3, UnitQuaternion, Vector3,
+};
+
+use crate::d3::free::Vector;
+use crate::d3::round::Point;
+use crate::d3::transform::{Dilator, Motor, Rotor, Translator};
+use crate::{Euclidean, Field, Space};
+
+impl<T: Field + Copy> From<Vector3<T>> for Vector<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Vector::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Field + Copy> From<Vector<T>> for Vector3<T> {
+    #[inline]
+    fn from(v: Vector<T>) -> Self {
+        Vector3::new(v.e1, v.e2, v.e3)
+    }
+}
+
+impl<T: Field + Copy, S: Space> From<Point3<T>> for Point<T, S> {
+    #[inline]
+    fn from(p: Point3<T>) -> Self {
+        Point::new(Vector::new(p.x, p.y, p.z))
+    }
+}
+
+impl<T: Field + Copy, S: Space> From<Point<T, S>> for Point3<T> {
+    #[inline]
+    fn from(p: Point<T, S>) -> Self {
+        Vector3::from(p.position()).into()
+    }
+}
+
+impl<T: Field + Copy> From<UnitQuaternion<T>> for Rotor<T>
+where
+    T::Element: Field,
+{
+    #[inline]
+    fn from(q: UnitQuaternion<T>) -> Self {
+        Rotor::from_quaternion([q.w, q.i, q.j, q.k])
+    }
+}
+
+impl<T: Field + Copy> From<Rotor<T>> for UnitQuaternion<T>
+where
+    T::Element: Field,
+{
+    /// Normalizes the rotor, since every `UnitQuaternion` must have unit norm.
+    #[inline]
+    fn from(r: Rotor<T>) -> Self {
+        let [w, x, y, z] = r.to_quaternion();
+        UnitQuaternion::from_quaternion(Quaternion::new(w, x, y, z))
+    }
+}
+
+impl<T: Field + Copy> From<Rotation3<T>> for Rotor<T>
+where
+    T::Element: Field,
+{
+    #[inline]
+    fn from(r: Rotation3<T>) -> Self {
+        UnitQuaternion::from_rotation_matrix(&r).into()
+    }
+}
+
+impl<T: Field + Copy> From<Rotor<T>> for Rotation3<T>
+where
+    T::Element: Field,
+{
+    #[inline]
+    fn from(r: Rotor<T>) -> Self {
+        UnitQuaternion::from(r).to_rotation_matrix()
+    }
+}
+
+impl<T: Field + Copy> From<Translation3<T>> for Translator<T> {
+    #[inline]
+    fn from(t: Translation3<T>) -> Self {
+        Vector::from(t.vector).into_translator::<Euclidean>()
+    }
+}
+
+impl<T: Field + Copy> From<Translator<T>> for Translation3<T> {
+    #[inline]
+    fn from(t: Translator<T>) -> Self {
+        // A translator by `v` is 1 - ½ v ni, up to its scalar weight.
+        let n2 = T::from_subset(&-2.0) / t.s;
+        Translation3::new(t.e1i * n2, t.e2i * n2, t.e3i * n2)
+    }
+}
+
+impl<T: Field + Copy> From<Isometry3<T>> for Motor<T>
+where
+    T::Element: Field,
+{
+    #[inline]
+    fn from(iso: Isometry3<T>) -> Self {
+        Motor::new(iso.rotation.into(), iso.translation.vector.into())
+    }
+}
+
+impl<T: Field + Copy> From<Motor<T>> for Isometry3<T>
+where
+    T::Element: Field,
+{
+    #[inline]
+    fn from(m: Motor<T>) -> Self {
+        let translation = Translation3::from(Vector3::from(m.translation()));
+        Isometry3::from_parts(translation, m.rotor().into())
+    }
+}
+
+impl<T: Field + Copy> Motor<T>
+where
+    T::Element: Field,
+{
+    /// Splits a similarity into the dilator it applies first and the motor it applies after.
+    #[inline]
+    pub fn from_similarity3(sim: Similarity3<T>) -> (Self, Dilator<T>) {
+        (sim.isometry.into(), Dilator::new(sim.scaling()))
+    }
+
+    /// Combines this motor with a dilator applied before it into a similarity.
+    #[inline]
+    pub fn to_similarity3(self, dilator: Dilator<T>) -> Similarity3<T> {
+        Similarity3::from_isometry(self.into(), dilator.scale())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::d3::Transform;
+    use crate::Multivec;
+    use approx::assert_relative_eq;
+    use simba::simd::{AutoF64x4, SimdValue};
+
+    fn assert_vec_eq(a: Vector3<f64>, b: Vector3<f64>) {
+        assert_relative_eq!(a.x, b.x, epsilon = 1e-12);
+        assert_relative_eq!(a.y, b.y, epsilon = 1e-12);
+        assert_relative_eq!(a.z, b.z, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_points() {
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        assert_eq!(Vector3::from(Vector::from(v)), v);
+        let p = Point3::new(1.0, -2.0, 0.5);
+        assert_eq!(Point3::from(Point::<_, crate::Hyperbolic>::from(p)), p);
+        assert_eq!(Point3::from(Point::<_>::from(p)), p);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let q = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 0.8);
+        let r = Rotor::from(q);
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        assert_vec_eq(r.transform(Vector::from(v)).into(), q * v);
+        assert_relative_eq!(UnitQuaternion::from(r), q, epsilon = 1e-12);
+
+        let rot = Rotation3::from_euler_angles(0.3, -1.2, 2.0);
+        let r = Rotor::from(rot);
+        assert_vec_eq(r.transform(Vector::from(v)).into(), rot * v);
+        assert_relative_eq!(Rotation3::from(r), rot, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_rigid() {
+        let t = Translation3::new(3.0, -1.0, 0.25);
+        let tr = Translator::from(t);
+        assert_eq!(Translation3::from(tr), t);
+
+        let iso = Isometry3::new(Vector3::new(3.0, -1.0, 0.25), Vector3::new(0.5, 1.0, -0.3));
+        let m = Motor::from(iso);
+        let p = Point3::new(1.0, -2.0, 0.5);
+        assert_vec_eq(Point3::from(m.transform(Point::from(p))).coords, (iso * p).coords);
+        assert_relative_eq!(Isometry3::from(m), iso, epsilon = 1e-12);
+
+        let sim = Similarity3::from_isometry(iso, 2.5);
+        let (m, d) = Motor::from_similarity3(sim);
+        let scaled = m.transform(d.transform(Point::from(p)));
+        assert_vec_eq(Point3::from(scaled).coords, (sim * p).coords);
+        assert_relative_eq!(m.to_similarity3(d), sim, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_simd() {
+        let q = UnitQuaternion::<AutoF64x4>::identity();
+        let r = Rotor::from(q);
+        assert_eq!(r.into_mv().s, AutoF64x4::splat(1.0));
+        let v = Vector3::<AutoF64x4>::repeat(AutoF64x4::splat(2.0));
+        assert_eq!(Vector3::from(r.transform(Vector::from(v))), v);
+    }
+}
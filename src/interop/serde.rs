@@ -0,0 +1,337 @@
+//! Objects serialize as structs of their named blade components, using the same names as the
+//! general multivector, e.g. `{"space": "Euclidean", "e1": 1.0, ..., "en": 0.5}`. Objects that
+//! depend on a `Space` carry it in the `space` field, and flats list both the `p` and `n` halves
+//! of each blade containing infinity. Deserialization rejects components that the space can't
+//! represent, such as a `Euclidean` line whose `e12p` and `e12n` differ.
+
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+
+use ::serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{SerializeStruct, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use num_traits::zero;
+
+use crate::d3::direction::DVector;
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::free::{Bivector, Trivector, Vector};
+use crate::d3::round::{Circle, Pair, Point, Sphere};
+use crate::d3::transform::{Dilator, Motor, Rotor, Translator};
+use crate::{try_from_mv, Euclidean, Field, Multivec, Space, R410};
+
+/// The name of the field holding the `Space` tag.
+const SPACE: &str = "space";
+
+/// Deserializes a field name into its index in `fields`.
+struct FieldSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldSeed {
+    type Value = usize;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldSeed {
+    type Value = usize;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "one of {:?}", self.0)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|&f| f == v)
+            .ok_or_else(|| E::unknown_field(v, self.0))
+    }
+}
+
+/// Checks that the `space` field names the expected space.
+struct SpaceSeed(&'static str);
+
+impl<'de> DeserializeSeed<'de> for SpaceSeed {
+    type Value = ();
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SpaceSeed {
+    type Value = ();
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "the space {:?}", self.0)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// Reads the components listed in `fields` into an array, after the `space` tag if there is one.
+struct BladeVisitor<T, const N: usize> {
+    name: &'static str,
+    fields: &'static [&'static str],
+    space: Option<&'static str>,
+    _pd: PhantomData<T>,
+}
+
+impl<T, const N: usize> BladeVisitor<T, N> {
+    /// The index of the first blade within `fields`.
+    fn offset(&self) -> usize {
+        self.space.is_some() as usize
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Copy, const N: usize> Visitor<'de> for BladeVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a {}", self.name)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+        if let Some(space) = self.space {
+            seq.next_element_seed(SpaceSeed(space))?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        }
+        let mut values = [None; N];
+        for (i, value) in values.iter_mut().enumerate() {
+            let len = i + self.offset();
+            *value = Some(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(len, &self))?);
+        }
+        Ok(values.map(Option::unwrap))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<[T; N], A::Error> {
+        let offset = self.offset();
+        let mut space_seen = false;
+        let mut values = [None; N];
+        while let Some(i) = map.next_key_seed(FieldSeed(self.fields))? {
+            if i < offset {
+                if space_seen {
+                    return Err(de::Error::duplicate_field(SPACE));
+                }
+                map.next_value_seed(SpaceSeed(self.space.unwrap()))?;
+                space_seen = true;
+            } else if values[i - offset].is_some() {
+                return Err(de::Error::duplicate_field(self.fields[i]));
+            } else {
+                values[i - offset] = Some(map.next_value()?);
+            }
+        }
+        if offset > 0 && !space_seen {
+            return Err(de::Error::missing_field(SPACE));
+        }
+        for (i, value) in values.iter().enumerate() {
+            if value.is_none() {
+                return Err(de::Error::missing_field(self.fields[i + offset]));
+            }
+        }
+        Ok(values.map(Option::unwrap))
+    }
+}
+
+/// Rebuilds an object from its blades, failing if it can't hold them all exactly.
+fn from_blades<X, E>(mv: R410<X::Element>, name: &str, space: &str) -> Result<X, E>
+where
+    X: Multivec + Copy,
+    X::Element: PartialEq,
+    E: de::Error,
+{
+    try_from_mv(mv).ok_or_else(|| {
+        E::custom(format_args!(
+            "inconsistent components for a {} in {} space",
+            name, space
+        ))
+    })
+}
+
+macro_rules! impl_serde {
+    ($t:ident<S> {$($blade:ident),*}) => {
+        impl<T: Field + Copy + Serialize, S: Space> Serialize for $t<T, S> {
+            fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                let mv = self.into_mv();
+                let len = [$(stringify!($blade)),*].len() + 1;
+                let mut state = serializer.serialize_struct(stringify!($t), len)?;
+                state.serialize_field(SPACE, S::NAME)?;
+                $(state.serialize_field(stringify!($blade), &mv.$blade)?;)*
+                state.end()
+            }
+        }
+
+        impl<'de, T: Field + Copy + Deserialize<'de>, S: Space> Deserialize<'de> for $t<T, S> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[SPACE, $(stringify!($blade)),*];
+                const N: usize = FIELDS.len() - 1;
+                let visitor = BladeVisitor::<T, N> {
+                    name: stringify!($t),
+                    fields: FIELDS,
+                    space: Some(S::NAME),
+                    _pd: PhantomData,
+                };
+                let values = deserializer.deserialize_struct(stringify!($t), FIELDS, visitor)?;
+                let mut values = values.iter().copied();
+                let mut mv: R410<T> = zero();
+                $(mv.$blade = values.next().unwrap();)*
+                from_blades::<Self, D::Error>(mv, stringify!($t), S::NAME)
+            }
+        }
+    };
+    ($t:ident {$($blade:ident),*}) => {
+        impl<T: Field + Copy + Serialize> Serialize for $t<T> {
+            fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                let mv = self.into_mv();
+                let len = [$(stringify!($blade)),*].len();
+                let mut state = serializer.serialize_struct(stringify!($t), len)?;
+                $(state.serialize_field(stringify!($blade), &mv.$blade)?;)*
+                state.end()
+            }
+        }
+
+        impl<'de, T: Field + Copy + Deserialize<'de>> Deserialize<'de> for $t<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($blade)),*];
+                const N: usize = FIELDS.len();
+                let visitor = BladeVisitor::<T, N> {
+                    name: stringify!($t),
+                    fields: FIELDS,
+                    space: None,
+                    _pd: PhantomData,
+                };
+                let values = deserializer.deserialize_struct(stringify!($t), FIELDS, visitor)?;
+                let mut values = values.iter().copied();
+                let mut mv: R410<T> = zero();
+                $(mv.$blade = values.next().unwrap();)*
+                from_blades::<Self, D::Error>(mv, stringify!($t), Euclidean::NAME)
+            }
+        }
+    };
+}
+
+impl_serde!(Point<S> {e1, e2, e3, ep, en});
+impl_serde!(Pair<S> {e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn});
+impl_serde!(Circle<S> {e123, e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn});
+impl_serde!(Sphere<S> {e123p, e123n, e12pn, e13pn, e23pn});
+impl_serde!(FPoint<S> {e1p, e1n, e2p, e2n, e3p, e3n, epn});
+impl_serde!(Line<S> {e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn});
+impl_serde!(Plane<S> {e123p, e123n, e12pn, e13pn, e23pn});
+impl_serde!(DLine<S> {e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n});
+impl_serde!(DPlane<S> {e1, e2, e3, ep, en});
+impl_serde!(DSphere<S> {e1, e2, e3, ep, en});
+impl_serde!(DVector<S> {e1p, e1n, e2p, e2n, e3p, e3n});
+impl_serde!(Translator<S> {s, e1p, e1n, e2p, e2n, e3p, e3n});
+impl_serde!(Vector {e1, e2, e3});
+impl_serde!(Bivector {e12, e13, e23});
+impl_serde!(Trivector {e123});
+impl_serde!(Rotor {s, e12, e13, e23});
+impl_serde!(Motor {s, e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, e123p, e123n});
+impl_serde!(Dilator {s, epn});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Hyperbolic, Outer};
+
+    fn round_trip<X>(obj: X)
+    where
+        X: Multivec<Element = f64> + Copy + Serialize + for<'de> Deserialize<'de>,
+    {
+        let json = serde_json::to_string(&obj).unwrap();
+        let back: X = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.into_mv(), obj.into_mv(), "{}", json);
+    }
+
+    fn round_trip_space<S: Space>() {
+        let p1 = Point::<f64, S>::new([1.0, 0.0, 0.0]);
+        let p2 = Point::new([3.0, 4.0, 5.0]);
+        let p3 = Point::new([-2.0, 1.0, 0.5]);
+        let pair = p1.outer(p2);
+        let circle = pair.outer(p3);
+        let d1: DPlane<f64, S> = Vector::new(1.0, 2.0, 3.0).normal(1.5);
+        let d2: DPlane<f64, S> = Vector::new(0.0, -1.0, 1.0).normal(-0.5);
+
+        round_trip(p1);
+        round_trip(pair);
+        round_trip(circle);
+        round_trip(circle.outer(Point::new([0.0, -1.0, 2.0])));
+        round_trip(p1.into_flat());
+        round_trip(pair.extend());
+        round_trip(circle.extend());
+        round_trip(DLine::<f64, S>::from_mv(d1.into_mv() ^ d2.into_mv()));
+        round_trip(d1);
+        round_trip(p2.into_sphere(2.0));
+        round_trip(Vector::new(1.0, -2.0, 0.5).as_direction::<S>());
+        round_trip(Vector::new(1.0, -2.0, 0.5).into_translator::<S>());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip_space::<Euclidean>();
+        round_trip_space::<Hyperbolic>();
+        round_trip_space::<crate::Spherical>();
+
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        round_trip(Vector::new(1.0, -2.0, 0.5));
+        round_trip(Bivector { e12: 1.0, e13: 2.0, e23: 3.0 });
+        round_trip(Trivector { e123: 4.0 });
+        round_trip(r);
+        round_trip(Motor::new(r, Vector::new(3.0, -1.0, 0.25)));
+        round_trip(Dilator::new(2.0));
+    }
+
+    #[test]
+    fn test_format() {
+        let p = Point::<f64>::new([1.0, 2.0, 2.0]);
+        assert_eq!(
+            serde_json::to_string(&p).unwrap(),
+            r#"{"space":"Euclidean","e1":1.0,"e2":2.0,"e3":2.0,"ep":4.0,"en":5.0}"#
+        );
+        let l: Line<f64> = serde_json::from_str(
+            r#"{"space":"Euclidean","e12p":1,"e12n":1,"e13p":0,"e13n":0,"e23p":0,"e23n":0,
+                "e1pn":0,"e2pn":0,"e3pn":1}"#,
+        )
+        .unwrap();
+        assert_eq!(l.into_mv().e12n, 1.0);
+
+        // Structs can also be read as sequences in field order.
+        let v: Vector<f64> = serde_json::from_str("[1, 2, 3]").unwrap();
+        assert_eq!(v, Vector::new(1.0, 2.0, 3.0));
+        let p: Point<f64> = serde_json::from_str(r#"["Euclidean", 1, 2, 2, 4, 5]"#).unwrap();
+        assert_eq!(p.position(), Vector::new(1.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_validation() {
+        let bad_flat = r#"{"space":"Euclidean","e12p":1,"e12n":2,"e13p":0,"e13n":0,"e23p":0,
+            "e23n":0,"e1pn":0,"e2pn":0,"e3pn":1}"#;
+        let err = serde_json::from_str::<Line<f64>>(bad_flat).unwrap_err();
+        assert!(err.to_string().contains("inconsistent"), "{}", err);
+
+        let hyperbolic = r#"{"space":"Hyperbolic","e12p":1,"e12n":1,"e13p":0,"e13n":0,"e23p":0,
+            "e23n":0,"e1pn":0,"e2pn":0,"e3pn":1}"#;
+        assert!(serde_json::from_str::<Line<f64, Hyperbolic>>(hyperbolic).is_err());
+
+        let wrong_space = r#"{"space":"Hyperbolic","e1":0,"e2":0,"e3":0,"ep":0,"en":1}"#;
+        assert!(serde_json::from_str::<Point<f64>>(wrong_space).is_err());
+        assert!(serde_json::from_str::<Point<f64, Hyperbolic>>(wrong_space).is_ok());
+
+        let missing = r#"{"space":"Euclidean","e1":0,"e2":0,"e3":0,"ep":0}"#;
+        let err = serde_json::from_str::<Point<f64>>(missing).unwrap_err();
+        assert!(err.to_string().contains("missing field `en`"), "{}", err);
+
+        let unknown = r#"{"e1":0,"e2":0,"e3":0,"e4":0}"#;
+        assert!(serde_json::from_str::<Vector<f64>>(unknown).is_err());
+
+        let no_space = r#"{"e1":0,"e2":0,"e3":0,"ep":0,"en":1}"#;
+        assert!(serde_json::from_str::<Point<f64>>(no_space).is_err());
+
+        // Motors are always Euclidean.
+        let r = Rotor::<f64>::identity();
+        let motor = serde_json::to_string(&Motor::from(r)).unwrap().replace(r#""e1n":0.0"#, r#""e1n":1.0"#);
+        assert!(serde_json::from_str::<Motor<f64>>(&motor).is_err());
+    }
+}
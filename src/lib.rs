@@ -1,16 +1,18 @@
 #![cfg_attr(feature = "unstable", feature(core_intrinsics))]
+#![cfg_attr(feature = "unstable", allow(unknown_lints, internal_features))]
 #![cfg_attr(all(feature = "unstable", feature = "repr_simd"), feature(repr_simd))]
 #[cfg(all(feature = "repr_simd", not(feature = "unstable")))]
 compile_error!("#[repr(simd)] requires unstable.");
 
 use num_traits::{zero};
+use simba::scalar::RealField;
 use simba::simd::SimdRealField as Field;
 
 #[cfg(feature = "unstable")]
 mod fast_math;
 
 #[cfg(feature = "unstable")]
-pub use fast_math::F32;
+pub use fast_math::{BoolxN, F32xN, F64xN, F32, F64};
 
 mod r410;
 use r410::R410;
@@ -18,11 +20,24 @@ use r410::R410;
 #[macro_use]
 mod traits;
 
+mod classify;
 pub mod d3;
+mod error;
+pub mod ganja;
+mod interop;
+mod normalize;
+mod params;
+mod parse;
+pub mod shader;
 
 mod spaces;
 
 
+pub use classify::{classify, ObjectKind, Reality};
+pub use error::Error;
+pub use normalize::Normalize;
+pub use params::ObjectParams;
+pub use parse::ParseError;
 pub use spaces::{Euclidean, Hyperbolic, Space, Spherical};
 
 /// A constant zero to save on calculations and space when using general multivectors.
@@ -51,10 +66,57 @@ mod sealed {
         type Element: Field + Copy;
         fn into_mv(self) -> R410<Self::Element>;
         fn from_mv(v: R410<Self::Element>) -> Self;
+
+        /// Whether the halves of each blade containing infinity that `from_mv` joins into a
+        /// single component agree with the object's space.
+        #[inline]
+        fn joins(_v: &R410<Self::Element>) -> bool
+        where
+            Self::Element: PartialEq,
+        {
+            true
+        }
     }
 }
 use sealed::Multivec;
 
+/// Converts a general multivector into the object `X`, or `None` if `X` can't hold all of it.
+#[inline]
+pub(crate) fn try_from_mv<X: Multivec + Copy>(v: R410<X::Element>) -> Option<X>
+where
+    X::Element: PartialEq,
+{
+    if !X::joins(&v) {
+        return None;
+    }
+    let x = X::from_mv(v);
+    if x.into_mv() == v {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Compares the directions of the coefficient vectors of `a` and `b`, ignoring their lengths and
+/// signs.
+pub(crate) fn eq_projective<T, X>(a: X, b: X, epsilon: T) -> bool
+where
+    T: RealField + Copy,
+    X: Multivec<Element = T>,
+{
+    let a = a.into_mv().into_array();
+    let b = b.into_mv().into_array();
+    let dot = |u: &[T; 32], v: &[T; 32]| {
+        u.iter().zip(v.iter()).fold(zero(), |acc: T, (&x, &y)| acc + x * y)
+    };
+    let (norm_a, norm_b) = (dot(&a, &a).sqrt(), dot(&b, &b).sqrt());
+    if norm_a.is_zero() || norm_b.is_zero() {
+        return norm_a.is_zero() && norm_b.is_zero();
+    }
+    let scale = if dot(&a, &b) < zero() { -norm_a / norm_b } else { norm_a / norm_b };
+    a.iter().zip(b.iter()).all(|(&x, &y)| (x - y * scale).abs() <= epsilon * norm_a)
+}
+
 impl<T: Field + Copy> Multivec for Scalar<T> {
     type Element = T;
     #[inline]
@@ -126,17 +188,23 @@ pub trait Outer<RHS: Multivec<Element = Self::Element> = Self>: Multivec {
 /// The anticommutative part of the geometric product.
 /// I didn't make these definitions. Technically 1/2 the actual commutator product.
 /// Can be calculated as (xy - yx)/2
-pub trait Commutator<RHS = Self> {
-    type Output;
-    fn commutator(self, rhs: RHS) -> Self::Output;
+pub trait Commutator<RHS: Multivec<Element = Self::Element> = Self>: Multivec {
+    type Output: Multivec<Element = Self::Element>;
+    #[inline]
+    fn commutator(self, rhs: RHS) -> Self::Output {
+        Multivec::from_mv(self.into_mv().commutator(rhs.into_mv()))
+    }
 }
 
 /// The commutative part of the geometric product.
 /// I didn't make these definitions. Technically 1/2 the actual anticommutator product.
 /// Can be calculated as (xy + yx)/2
-pub trait AntiCommutator<RHS = Self> {
-    type Output;
-    fn anticommutator(self, rhs: RHS) -> Self::Output;
+pub trait AntiCommutator<RHS: Multivec<Element = Self::Element> = Self>: Multivec {
+    type Output: Multivec<Element = Self::Element>;
+    #[inline]
+    fn anticommutator(self, rhs: RHS) -> Self::Output {
+        Multivec::from_mv(self.into_mv().anticommutator(rhs.into_mv()))
+    }
 }
 
 impl<V, W> Meet<W> for V
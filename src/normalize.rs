@@ -0,0 +1,215 @@
+//! Conformal objects are only defined up to a scalar weight: `P` and `2P` are the same point, and
+//! `X` and `-X` the same object with opposite orientations.
+//!
+//! The weight of an object is read off its Euclidean attitude, following Dorst, Fontijne and Mann:
+//!
+//! * Rounds (points, pairs, circles, spheres and dual spheres) have the attitude `-ni⌋X`.
+//!   For a point this is the familiar `-ni·P`.
+//! * Flats have the attitude `E` in `X = no∧E∧ni + ...`, the part of the object along `no∧ni`.
+//! * Dual flats `E + d ni` have the attitude `E` directly.
+//!
+//! When the attitude is a single scalar or `e123` component the weight keeps its sign, so that
+//! normalizing also fixes the orientation of points and spheres. Otherwise it is the length of
+//! the attitude, and normalizing preserves directions.
+//!
+//! In hyperbolic and spherical space `ni` isn't null, so `-ni⌋X` above stands for contraction
+//! with the reciprocal of `no`, the vector that picks out the coefficient of `no` and ignores
+//! `ni`. In Euclidean space the two coincide.
+
+use num_traits::{zero, Zero};
+use simba::scalar::RealField;
+
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::round::{Circle, Pair, Point, Sphere};
+use crate::r410::{blade_index, BLADE_MASKS};
+use crate::spaces::IBasis;
+use crate::{eq_projective, Field, Multivec, Space, R410};
+
+/// The bitmask of `ep∧en`.
+pub(crate) const PN_MASK: u8 = 0b11000;
+
+/// Normalization of objects that are only defined up to a scalar weight.
+pub trait Normalize: Multivec + Copy {
+    /// The weight of the object, which is 1 for normalized objects. See the module documentation
+    /// for the conventions used.
+    fn weight(self) -> Self::Element;
+
+    /// Divides the object by its weight. The result has non-finite components if the weight is
+    /// zero, as it is for objects at infinity.
+    #[inline]
+    fn normalized(self) -> Self {
+        Self::from_mv(self.into_mv() / self.weight())
+    }
+
+    /// Divides the object by its weight, or returns `None` if the weight is zero.
+    #[inline]
+    fn try_normalized(self) -> Option<Self>
+    where
+        Self::Element: RealField,
+    {
+        let weight = self.weight();
+        if weight.is_zero() {
+            None
+        } else {
+            Some(Self::from_mv(self.into_mv() / weight))
+        }
+    }
+
+    /// Whether both objects are the same up to a nonzero scale, including a change of sign.
+    ///
+    /// `epsilon` is the tolerance on each component after scaling both to unit length, so this
+    /// also works for objects of zero weight.
+    #[inline]
+    fn eq_projective(self, other: Self, epsilon: Self::Element) -> bool
+    where
+        Self::Element: RealField,
+    {
+        eq_projective(self, other, epsilon)
+    }
+}
+
+/// The weight of a Euclidean blade, signed when it has a single component.
+#[inline]
+fn attitude_weight<T: Field + Copy>(e: R410<T>) -> T {
+    // A blade has only one grade, so at most one of the terms below is nonzero.
+    let vector = e.e1 * e.e1 + e.e2 * e.e2 + e.e3 * e.e3;
+    let bivector = e.e12 * e.e12 + e.e13 * e.e13 + e.e23 * e.e23;
+    e.s + e.e123 + (vector + bivector).simd_sqrt()
+}
+
+/// Drops every component containing `ep` or `en`.
+#[inline]
+fn euclidean_part<T: Field + Copy>(v: R410<T>) -> R410<T> {
+    let mut a = v.into_array();
+    for (c, mask) in a.iter_mut().zip(BLADE_MASKS.iter()) {
+        if mask & PN_MASK != 0 {
+            *c = zero();
+        }
+    }
+    R410::from_array(a)
+}
+
+/// The vector `r` with `r·no = -1` and `r·ni = 0`, which is just `ni` in Euclidean space.
+#[inline]
+fn no_reciprocal<T: Field + Copy, S: Space>() -> R410<T> {
+    let IBasis { ep: a, en: b } = S::split(T::one());
+    let two = T::from_subset(&2.0);
+    R410 {
+        ep: two * b / (a + b),
+        en: two * a / (a + b),
+        ..zero()
+    }
+}
+
+/// The Euclidean attitude `E` of a round, from `-ni⌋X = E + ...`.
+#[inline]
+pub(crate) fn round_attitude<T: Field + Copy, S: Space>(x: R410<T>) -> R410<T> {
+    // -r⌋X, written out as the antisymmetric part of the product for a vector r.
+    let r = no_reciprocal::<T, S>();
+    let half = T::from_subset(&0.5);
+    euclidean_part((x.involute() * r - r * x) * half)
+}
+
+/// The Euclidean attitude `E` of a flat `X = no∧E∧ni + ...`.
+#[inline]
+pub(crate) fn flat_attitude<T: Field + Copy, S: Space>(x: R410<T>) -> R410<T> {
+    // no∧ni = -(a + b)/2 ep∧en, where ni = a ep + b en, and moving E past no flips the sign of
+    // odd grades.
+    let IBasis { ep: a, en: b } = S::split(T::one());
+    let scale = -T::from_subset(&2.0) / (a + b);
+    let x = x.into_array();
+    let mut attitude = [zero(); 32];
+    for mask in 0..8u8 {
+        let c = x[blade_index(mask | PN_MASK)] * scale;
+        attitude[blade_index(mask)] = if mask.count_ones() % 2 == 0 { c } else { -c };
+    }
+    R410::from_array(attitude)
+}
+
+/// The Euclidean attitude `E` of a dual flat `E + d ni`.
+#[inline]
+pub(crate) fn dual_flat_attitude<T: Field + Copy>(x: R410<T>) -> R410<T> {
+    euclidean_part(x)
+}
+
+macro_rules! impl_normalize {
+    ($attitude:expr => $($t:ident),*) => {$(
+        impl<T: Field + Copy, S: Space> Normalize for $t<T, S> {
+            #[inline]
+            fn weight(self) -> T {
+                attitude_weight($attitude(self.into_mv()))
+            }
+        }
+    )*};
+}
+
+impl_normalize!(round_attitude::<T, S> => Point, Pair, Circle, Sphere, DSphere);
+impl_normalize!(flat_attitude::<T, S> => FPoint, Line, Plane);
+impl_normalize!(dual_flat_attitude => DLine, DPlane);
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::d3::free::Vector;
+    use crate::{Euclidean, Hyperbolic, Outer, Spherical};
+
+    fn check_space<S: Space>() {
+        let p1 = Point::<f64, S>::new([1.0, 0.0, 0.0]);
+        let p2 = Point::new([4.0, 4.0, 0.0]);
+        let p3 = Point::new([1.0, 2.0, 0.0]);
+        let p4 = Point::new([1.0, 0.0, 3.0]);
+
+        assert_relative_eq!(p1.weight(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(Point::<f64, S>::from_mv(p1.into_mv() * -3.0).weight(), -3.0);
+        assert_relative_eq!(p1.outer(p2).weight(), 5.0, epsilon = 1e-12);
+        // Twice the area of the triangle.
+        assert_relative_eq!(p1.outer(p2).outer(p3).weight(), 6.0, epsilon = 1e-12);
+        let sphere = p1.outer(p2).outer(p3).outer(p4);
+        assert_relative_eq!(sphere.weight().abs(), 18.0, epsilon = 1e-12);
+        assert_relative_eq!(sphere.normalized().weight(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(p2.into_sphere(2.0).weight(), 1.0, epsilon = 1e-12);
+
+        assert_relative_eq!(p1.into_flat().weight(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(p1.outer(p2).extend().weight(), 5.0, epsilon = 1e-12);
+        assert_relative_eq!(p1.outer(p2).outer(p3).extend().weight(), 6.0, epsilon = 1e-12);
+        let d: DPlane<f64, S> = Vector::new(0.0, 3.0, 4.0).normal(2.0);
+        assert_relative_eq!(d.weight(), 5.0, epsilon = 1e-12);
+        let e: DPlane<f64, S> = Vector::new(1.0, 0.0, 0.0).normal(1.0);
+        let l = DLine::<f64, S>::from_mv(d.into_mv() ^ e.into_mv());
+        assert_relative_eq!(l.weight(), 5.0, epsilon = 1e-12);
+
+        let pair = p1.outer(p2);
+        let scaled = Pair::from_mv(pair.into_mv() * -0.5);
+        assert!(scaled.eq_projective(pair, 1e-12));
+        assert!(scaled.normalized().eq_projective(pair, 1e-12));
+        assert_relative_eq!(scaled.normalized().weight(), 1.0, epsilon = 1e-12);
+        assert!(!pair.eq_projective(p1.outer(p3), 1e-6));
+    }
+
+    #[test]
+    fn test_weight() {
+        check_space::<Euclidean>();
+        check_space::<Hyperbolic>();
+        check_space::<Spherical>();
+    }
+
+    #[test]
+    fn test_normalized() {
+        let q = Point::<f64>::new([1.0, 2.0, 3.0]);
+        let p = Point::from_mv(q.into_mv() * -2.0);
+        assert_relative_eq!(p.normalized(), q, epsilon = 1e-12);
+        assert!(p.eq_projective(q, 1e-12));
+
+        // The point at infinity and the line at infinity have no weight.
+        let ni = Point::<f64>::from_mv(Euclidean::infinity());
+        assert_eq!(ni.weight(), 0.0);
+        assert_eq!(ni.try_normalized(), None);
+        let horizon = Line::<f64>::from_mv(R410 { e12p: 1.0, e12n: 1.0, ..zero() });
+        assert_eq!(horizon.try_normalized(), None);
+        assert!(horizon.eq_projective(Line::from_mv(horizon.into_mv() * 3.0), 1e-12));
+        assert!(Plane::<f64>::from_mv(zero()).eq_projective(Plane::from_mv(zero()), 1e-12));
+    }
+}
@@ -0,0 +1,282 @@
+//! The generic parameters of round and flat blades from Dorst, Fontijne and Mann, chapter 14.
+//!
+//! Every round or flat `X` is described by its direction element `-ni⌋X∧ni = E∧ni`, where `E` is
+//! a Euclidean blade called the attitude, together with a location, a squared size and a weight.
+//! Dual objects are described by the parameters of the object they are the dual of, except that
+//! their attitude is the dual attitude, such as the normal vector of a plane.
+
+use simba::simd::SimdRealField as Field;
+
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::free::{Bivector, Trivector, Vector};
+use crate::d3::round::{origin, Circle, Pair, Point, Sphere};
+use crate::normalize::{dual_flat_attitude, flat_attitude, round_attitude, PN_MASK};
+use crate::r410::{blade_index, BLADE_MASKS};
+use crate::{Euclidean, Multivec, Normalize, Scalar, R410};
+
+/// The attitude, location and size of Euclidean rounds, flats and their duals. The weight comes
+/// from [`Normalize::weight`], and is the signed magnitude of the attitude.
+pub trait ObjectParams: Normalize {
+    /// The Euclidean blade `E` spanning the directions of the object.
+    type Attitude: Multivec<Element = Self::Element>;
+
+    /// The attitude `E`, scaled by the weight of the object.
+    fn attitude(self) -> Self::Attitude;
+
+    /// The center of a round, or the point of a flat closest to the origin.
+    fn location(self) -> Point<Self::Element>;
+
+    /// The squared radius of a round, negative for imaginary rounds and zero for flats.
+    fn size(self) -> Self::Element;
+}
+
+/// The bitmasks of `ep` and `en`.
+const P_MASK: u8 = 0b01000;
+const N_MASK: u8 = 0b10000;
+
+/// The Euclidean parts of `X = A + no∧B + ni∧C + no∧ni∧D`.
+///
+/// Rounds far from the origin have large `ep` and `en` components that nearly cancel in `no` and
+/// `ni`, so the products of the parameters are formed from these parts instead.
+#[inline]
+pub(crate) fn null_parts<T: Field + Copy>(x: R410<T>) -> [R410<T>; 4] {
+    let x = x.into_array();
+    let half = T::from_subset(&0.5);
+    let mut parts = [[T::zero(); 32]; 4];
+    for (i, &mask) in BLADE_MASKS.iter().enumerate() {
+        if mask & PN_MASK != 0 {
+            continue;
+        }
+        let p = x[blade_index(mask | P_MASK)];
+        let n = x[blade_index(mask | N_MASK)];
+        let sign = if mask.count_ones() % 2 == 0 { T::one() } else { -T::one() };
+        parts[0][i] = x[i];
+        parts[1][i] = (n - p) * sign;
+        parts[2][i] = (p + n) * half * sign;
+        parts[3][i] = -x[blade_index(mask | PN_MASK)];
+    }
+    parts.map(R410::from_array)
+}
+
+/// The center `c` and the `λ` of a round `T_c[(no + λ ni)∧E]`, whose squared size is `2λ`.
+///
+/// Splitting `c` into `u` orthogonal to `E` and `v` within it, the parts of [`null_parts`] are
+/// `B = E`, `A = uE`, `D = v⌋E = vE` and `C = (u²/2 - v²/2 + λ)E - u∧D`. `D` comes from products of
+/// the `ep` and `en` components, which lose `|c|` times more to round-off than those making up
+/// `C`, so `D` is also read from `u⌋C = -u² D` and the two are weighed by their round-off.
+#[inline]
+pub(crate) fn round_center<T: Field + Copy>(x: R410<T>) -> (Vector<T>, T) {
+    let [a, b, c, d] = null_parts(x);
+    let reverse = b.reverse();
+    let inverse = reverse / (b * reverse).s;
+    let vector = |x: R410<T>| Vector::<T>::from_mv(x).into_mv();
+    let half = T::from_subset(&0.5);
+    let quarter = T::from_subset(&0.25);
+
+    let u = vector(a * inverse);
+    let (u2, v2) = ((u * u).s, (vector(d * inverse) * vector(d * inverse)).s);
+    let weight = (u2 + v2 + T::one()) * quarter;
+    let contraction = (u * c - c.involute() * u) * half;
+    let d = (d - contraction * weight) / (T::one() + weight * u2);
+    let v = vector(d * inverse);
+
+    let v2 = (v * v).s;
+    let lambda = (c * inverse).s - u2 * half + v2 * half;
+    (Vector::from_mv(u + v), lambda)
+}
+
+/// The center of a round, which is where it sends infinity when used as a reflector.
+#[inline]
+fn round_location<T: Field + Copy>(x: R410<T>) -> Point<T> {
+    Point::new(round_center(x).0)
+}
+
+/// The squared radius `X X̂ / (ni⌋X)²` of a round.
+#[inline]
+pub(crate) fn round_size<T: Field + Copy>(x: R410<T>) -> T {
+    round_center(x).1 * T::from_subset(&2.0)
+}
+
+/// Dual rounds have the opposite sign, so that real spheres have a positive size either way.
+#[inline]
+fn dual_round_size<T: Field + Copy>(x: R410<T>) -> T {
+    -round_size(x)
+}
+
+#[inline]
+fn flat_size<T: Field + Copy>(_x: R410<T>) -> T {
+    T::zero()
+}
+
+/// The point of a flat closest to the origin, halfway between the origin and its mirror image.
+#[inline]
+fn flat_location<T: Field + Copy>(x: R410<T>) -> Point<T> {
+    let image = Point::<T>::from_mv(x * origin() * x.reverse()).normalized();
+    Point::new(image.position() * T::from_subset(&0.5))
+}
+
+macro_rules! impl_params {
+    ($attitude:expr, $location:ident, $size:ident: $($t:ident => $a:ident),*) => {$(
+        impl<T: Field + Copy> ObjectParams for $t<T> {
+            type Attitude = $a<T>;
+
+            #[inline]
+            fn attitude(self) -> $a<T> {
+                $a::from_mv($attitude(self.into_mv()))
+            }
+
+            #[inline]
+            fn location(self) -> Point<T> {
+                $location(self.into_mv())
+            }
+
+            #[inline]
+            fn size(self) -> T {
+                $size(self.into_mv())
+            }
+        }
+    )*};
+}
+
+impl_params!(
+    round_attitude::<T, Euclidean>, round_location, round_size:
+    Pair => Vector, Circle => Bivector, Sphere => Trivector
+);
+impl_params!(round_attitude::<T, Euclidean>, round_location, dual_round_size: DSphere => Scalar);
+impl_params!(
+    flat_attitude::<T, Euclidean>, flat_location, flat_size:
+    FPoint => Scalar, Line => Vector, Plane => Bivector
+);
+impl_params!(dual_flat_attitude, flat_location, flat_size: DLine => Bivector, DPlane => Vector);
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::Outer;
+
+    fn point(x: f64, y: f64, z: f64) -> Point<f64> {
+        Point::new([x, y, z])
+    }
+
+    #[test]
+    fn test_rounds() {
+        let pair = point(1.0, 2.0, 0.0).outer(point(1.0, 2.0, 4.0));
+        assert_relative_eq!(pair.attitude(), Vector::new(0.0, 0.0, 4.0), epsilon = 1e-12);
+        assert_relative_eq!(pair.location(), point(1.0, 2.0, 2.0), epsilon = 1e-12);
+        assert_relative_eq!(pair.size(), 4.0, epsilon = 1e-12);
+        assert_relative_eq!(pair.weight(), 4.0, epsilon = 1e-12);
+
+        let circle = point(2.0, 0.0, 3.0)
+            .outer(point(0.0, 2.0, 3.0))
+            .outer(point(-2.0, 0.0, 3.0));
+        assert_relative_eq!(circle.location(), point(0.0, 0.0, 3.0), epsilon = 1e-12);
+        assert_relative_eq!(circle.size(), 4.0, epsilon = 1e-12);
+        assert_relative_eq!(circle.attitude().e12.abs(), 8.0, epsilon = 1e-12);
+
+        let sphere = point(2.0, 2.0, 3.0)
+            .outer(point(0.0, 2.0, 3.0))
+            .outer(point(1.0, 3.0, 3.0))
+            .outer(point(1.0, 2.0, 4.0));
+        assert_relative_eq!(sphere.location(), point(1.0, 2.0, 3.0), epsilon = 1e-12);
+        assert_relative_eq!(sphere.size(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(sphere.attitude().e123, sphere.weight(), epsilon = 1e-12);
+
+        let dual = point(1.0, -1.0, 0.5).into_sphere(3.0);
+        assert_relative_eq!(dual.location(), point(1.0, -1.0, 0.5), epsilon = 1e-12);
+        assert_relative_eq!(dual.size(), 9.0, epsilon = 1e-12);
+        assert_relative_eq!(dual.attitude().0, 1.0, epsilon = 1e-12);
+
+        // The dual of a real circle is an imaginary pair with the same center and radius.
+        let imaginary = Pair::<f64>::from_mv(circle.into_mv().dual());
+        assert_relative_eq!(imaginary.location(), point(0.0, 0.0, 3.0), epsilon = 1e-12);
+        assert_relative_eq!(imaginary.size(), -4.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_far_rounds() {
+        let x = 1e3;
+        let pair = point(x + 1.0, 2.0, 0.0).outer(point(x + 1.0, 2.0, 4.0));
+        let expected = Vector::new(x + 1.0, 2.0, 2.0);
+        assert_relative_eq!(pair.location().position(), expected, epsilon = 1e-6);
+        assert_relative_eq!(pair.size(), 4.0, epsilon = 1e-6);
+
+        let along = point(x, 0.0, 0.0).outer(point(x + 1.0, 0.0, 0.0));
+        let expected = Vector::new(x + 0.5, 0.0, 0.0);
+        assert_relative_eq!(along.location().position(), expected, epsilon = 1e-6);
+        assert_relative_eq!(along.size(), 0.25, epsilon = 1e-6);
+
+        let circle = point(x + 2.0, 0.0, 3.0)
+            .outer(point(x, 2.0, 3.0))
+            .outer(point(x - 2.0, 0.0, 3.0));
+        assert_relative_eq!(circle.location().position(), Vector::new(x, 0.0, 3.0), epsilon = 1e-6);
+        assert_relative_eq!(circle.size(), 4.0, epsilon = 1e-6);
+
+        let sphere = point(x + 2.0, 2.0, 3.0)
+            .outer(point(x, 2.0, 3.0))
+            .outer(point(x + 1.0, 3.0, 3.0))
+            .outer(point(x + 1.0, 2.0, 4.0));
+        let expected = Vector::new(x + 1.0, 2.0, 3.0);
+        assert_relative_eq!(sphere.location().position(), expected, epsilon = 1e-6);
+        assert_relative_eq!(sphere.size(), 1.0, epsilon = 1e-6);
+
+        let dual = point(x, -1.0, 0.5).into_sphere(3.0);
+        assert_relative_eq!(dual.location().position(), Vector::new(x, -1.0, 0.5), epsilon = 1e-6);
+        assert_relative_eq!(dual.size(), 9.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_far_rounds_f32() {
+        let point = |x: f32, y: f32, z: f32| Point::<f32>::new([x, y, z]);
+        for x in [100.0, -100.0] {
+            let pair = point(x, 2.0, 0.0).outer(point(x, 2.0, 4.0));
+            let expected = Vector::new(x, 2.0, 2.0);
+            assert_relative_eq!(pair.location().position(), expected, epsilon = 1e-3);
+            assert_relative_eq!(pair.size(), 4.0, epsilon = 1e-3);
+
+            let circle = point(x + 2.0, 0.0, 3.0)
+                .outer(point(x, 2.0, 3.0))
+                .outer(point(x - 2.0, 0.0, 3.0));
+            let expected = Vector::new(x, 0.0, 3.0);
+            assert_relative_eq!(circle.location().position(), expected, epsilon = 1e-3);
+            assert_relative_eq!(circle.size(), 4.0, epsilon = 1e-3);
+
+            let dual = point(x, -1.0, 0.5).into_sphere(3.0);
+            let expected = Vector::new(x, -1.0, 0.5);
+            assert_relative_eq!(dual.location().position(), expected, epsilon = 1e-3);
+            assert_relative_eq!(dual.size(), 9.0, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_flats() {
+        let p = point(1.0, 2.0, 3.0);
+        assert_relative_eq!(p.into_flat().location(), p, epsilon = 1e-12);
+        assert_relative_eq!(p.into_flat().attitude().0, 1.0, epsilon = 1e-12);
+        assert_eq!(p.into_flat().size(), 0.0);
+
+        let line = point(1.0, 0.0, 0.0).outer(point(1.0, 2.0, 0.0)).extend();
+        assert_relative_eq!(line.location(), point(1.0, 0.0, 0.0), epsilon = 1e-12);
+        assert_relative_eq!(line.attitude(), Vector::new(0.0, 2.0, 0.0), epsilon = 1e-12);
+        assert_eq!(line.size(), 0.0);
+
+        let plane = point(5.0, 1.0, 2.0)
+            .outer(point(6.0, 1.0, 2.0))
+            .outer(point(5.0, 2.0, 2.0))
+            .extend();
+        assert_relative_eq!(plane.location(), point(0.0, 0.0, 2.0), epsilon = 1e-12);
+        assert_relative_eq!(plane.attitude().e12.abs(), 1.0, epsilon = 1e-12);
+
+        let dual: DPlane<f64> = Vector::new(0.0, 0.0, 2.0).normal(4.0);
+        assert_relative_eq!(dual.location(), point(0.0, 0.0, 2.0), epsilon = 1e-12);
+        assert_relative_eq!(dual.attitude(), Vector::new(0.0, 0.0, 2.0), epsilon = 1e-12);
+
+        let other: DPlane<f64> = Vector::new(1.0, 0.0, 0.0).normal(3.0);
+        let dual = DLine::<f64>::from_mv(dual.into_mv() ^ other.into_mv());
+        assert_relative_eq!(dual.location(), point(3.0, 0.0, 2.0), epsilon = 1e-12);
+        assert_relative_eq!(dual.attitude().e13.abs(), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(dual.weight(), 2.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,237 @@
+//! Parsing of objects written as sums of blades, the inverse of their `Display`, `LowerExp` and
+//! `UpperExp` output.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::r410::BLADE_NAMES;
+use crate::{try_from_mv, Field, Multivec, R410};
+
+/// An error from parsing a sum of blades such as `1e1 - 0.5e2p`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A term isn't a number followed by an optional blade name.
+    InvalidTerm(String),
+    /// The sum is wrapped in the name of another type, e.g. `Pair(...)` when parsing a `Point`.
+    WrongType {
+        expected: &'static str,
+        found: String,
+    },
+    /// The sum has components that the type can't hold, such as blades of the wrong grade.
+    Unrepresentable(&'static str),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidTerm(term) => write!(f, "invalid term {:?}", term),
+            ParseError::WrongType { expected, found } => {
+                write!(f, "expected a {}, found a {}", expected, found)
+            }
+            ParseError::Unrepresentable(name) => {
+                write!(f, "the components don't form a valid {}", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Splits a sum into its signed terms. A `+` or `-` right after an `e` or `E` is the sign of an
+/// exponent rather than the start of a new term, since blade names never end in `e`.
+fn terms(s: &str) -> Vec<&str> {
+    let mut terms = Vec::new();
+    let mut start = 0;
+    let mut prev = None;
+    for (i, c) in s.char_indices() {
+        if (c == '+' || c == '-') && i > 0 && !matches!(prev, Some('e') | Some('E')) {
+            terms.push(&s[start..i]);
+            start = i;
+        }
+        prev = Some(c);
+    }
+    terms.push(&s[start..]);
+    terms
+}
+
+/// Parses a sum of blades into a general multivector.
+#[inline]
+pub(crate) fn parse_sum<T: Field + Copy + FromStr>(s: &str) -> Result<R410<T>, ParseError> {
+    parse_blades(s, &BLADE_NAMES)
+}
+
+/// Parses a sum of blades named by `names`, given in coefficient order.
+pub(crate) fn parse_blades<T: Field + Copy + FromStr>(
+    s: &str,
+    names: &[&str; 32],
+) -> Result<R410<T>, ParseError> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    if s.is_empty() {
+        return Err(ParseError::InvalidTerm(s));
+    }
+    let mut coefficients = [T::zero(); 32];
+    for term in terms(&s) {
+        let invalid = || ParseError::InvalidTerm(term.to_string());
+        let (negative, unsigned) = match term.as_bytes().first() {
+            Some(b'-') => (true, &term[1..]),
+            Some(b'+') => (false, &term[1..]),
+            _ => (false, term),
+        };
+
+        // Blade names start with their only `e`, or are `s` for the scalar part. A term without
+        // one is a plain scalar, which covers numbers like `1e0` too.
+        let blade = unsigned.rfind(['e', 's']).and_then(|i| {
+            let index = names.iter().position(|&b| b == &unsigned[i..])?;
+            Some((&unsigned[..i], index))
+        });
+        let (number, index) = blade.unwrap_or((unsigned, 0));
+        let value = if number.is_empty() && blade.is_some() {
+            T::one()
+        } else {
+            number.parse().map_err(|_| invalid())?
+        };
+        if negative {
+            coefficients[index] -= value;
+        } else {
+            coefficients[index] += value;
+        }
+    }
+    Ok(R410::from_array(coefficients))
+}
+
+/// Parses an object written either as a bare sum of blades or wrapped in its type name, like
+/// `Point(1e1 + 0.5en)`.
+pub(crate) fn parse_object<X>(name: &'static str, s: &str) -> Result<X, ParseError>
+where
+    X: Multivec + Copy,
+    X::Element: FromStr + PartialEq,
+{
+    let s = s.trim();
+    let sum = match (s.find('('), s.strip_suffix(')')) {
+        (Some(open), Some(inner)) => {
+            let found = s[..open].trim();
+            if found != name {
+                return Err(ParseError::WrongType {
+                    expected: name,
+                    found: found.to_string(),
+                });
+            }
+            &inner[open + 1..]
+        }
+        _ => s,
+    };
+    let mv = parse_sum(sum)?;
+    try_from_mv(mv).ok_or(ParseError::Unrepresentable(name))
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::{LowerExp, UpperExp};
+
+    use num_traits::zero;
+
+    use super::*;
+    use crate::d3::direction::DVector;
+    use crate::d3::dual::{DLine, DPlane, DSphere};
+    use crate::d3::flat::{FPoint, Line, Plane};
+    use crate::d3::free::{Bivector, Trivector, Vector};
+    use crate::d3::round::Point;
+    use crate::d3::transform::{Dilator, Motor, Rotor, Translator};
+    use crate::{Euclidean, Hyperbolic, Outer, Space, Spherical};
+
+    fn round_trip<X>(x: X)
+    where
+        X: Multivec<Element = f64> + Copy + Display + LowerExp + UpperExp + FromStr,
+        X::Err: core::fmt::Debug,
+    {
+        for s in &[format!("{}", x), format!("{:e}", x), format!("{:E}", x)] {
+            let back: X = s.parse().unwrap();
+            assert_eq!(back.into_mv(), x.into_mv(), "{}", s);
+        }
+        // The bare sum parses too.
+        let bare = format!("{}", x.into_mv());
+        assert_eq!(bare.parse::<X>().unwrap().into_mv(), x.into_mv(), "{}", bare);
+    }
+
+    fn round_trip_space<S: Space>() {
+        let p1 = Point::<f64, S>::new([1.0, 0.0, 0.0]);
+        let p2 = Point::new([3.0, 4.0, 5.0]);
+        let p3 = Point::new([-2.0, 1.0, 0.5]);
+        let pair = p1.outer(p2);
+        let circle = pair.outer(p3);
+        let d1: DPlane<f64, S> = Vector::new(1.0, 2.0, 3.0).normal(1.5);
+        let d2: DPlane<f64, S> = Vector::new(0.0, -1.0, 1.0).normal(-0.5);
+
+        round_trip(p1);
+        round_trip(pair);
+        round_trip(circle);
+        round_trip(circle.outer(Point::new([0.0, -1.0, 2.0])));
+        round_trip::<FPoint<f64, S>>(p1.into_flat());
+        round_trip::<Line<f64, S>>(pair.extend());
+        round_trip::<Plane<f64, S>>(circle.extend());
+        round_trip(DLine::<f64, S>::from_mv(d1.into_mv() ^ d2.into_mv()));
+        round_trip(d1);
+        round_trip::<DSphere<f64, S>>(p2.into_sphere(2.0));
+        round_trip::<DVector<f64, S>>(Vector::new(1.0, -2.0, 0.5).as_direction());
+        round_trip::<Translator<f64, S>>(Vector::new(1.0, -2.0, 0.5).into_translator());
+    }
+
+    #[test]
+    fn test_format() {
+        let p = Point::<f64>::new([1.0, -2.0, 2.0]);
+        assert_eq!(format!("{}", p), "Point(1e1 - 2e2 + 2e3 + 4ep + 5en)");
+        assert_eq!(format!("{:.1}", p), "Point(1.0e1 - 2.0e2 + 2.0e3 + 4.0ep + 5.0en)");
+        assert_eq!(format!("{:e}", p), "Point(1e0e1 - 2e0e2 + 2e0e3 + 4e0ep + 5e0en)");
+        assert_eq!(format!("{:E}", p), "Point(1E0e1 - 2E0e2 + 2E0e3 + 4E0ep + 5E0en)");
+        assert_eq!(format!("{}", Vector::new(0.0, 0.0, 0.0)), "Vector(0)");
+
+        // Scalars spell out positive exponents so they aren't read as blades.
+        let r = Rotor { s: 10.0, e12: -0.5, e13: 0.0, e23: 0.0 };
+        assert_eq!(format!("{:e}", r), "Rotor(1e+1 - 5e-1e12)");
+        assert_eq!(format!("{}", r), "Rotor(10 - 0.5e12)");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip_space::<Euclidean>();
+        round_trip_space::<Hyperbolic>();
+        round_trip_space::<Spherical>();
+
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        round_trip(Vector::new(1.0, -2.0, 0.5));
+        round_trip(Bivector { e12: 1.0, e13: 2.0, e23: 3.0 });
+        round_trip(Trivector { e123: 4e-20 });
+        round_trip(r);
+        round_trip(Rotor { s: 10.0, e12: 1e12, e13: 0.0, e23: -1e-7 });
+        round_trip(Motor::new(r, Vector::new(3.0, -1.0, 0.25)));
+        round_trip(Dilator::new(2.0));
+    }
+
+    #[test]
+    fn test_parse() {
+        let v: R410<f64> = "1 + 2e12 - e3pn + 0.5s".parse().unwrap();
+        assert_eq!(v, R410 { s: 1.5, e12: 2.0, e3pn: -1.0, ..zero() });
+        // Without a blade name, a trailing exponent is just part of the number.
+        assert_eq!("1e6".parse::<R410<f64>>(), Ok(R410 { s: 1e6, ..zero() }));
+        let v: R410<f64> = " -1e+1 + 1e1 - 1e-1e2 ".parse().unwrap();
+        assert_eq!(v, R410 { s: -10.0, e1: 1.0, e2: -0.1, ..zero() });
+
+        let p: Point<f64> = "Point(1e1 + en)".parse().unwrap();
+        assert_eq!(p, Point::new([1.0, 0.0, 0.0]));
+        let l: Line<f64> = "e12p + e12n".parse().unwrap();
+        assert_eq!(l.into_mv().e12n, 1.0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "Pair(1e1)".parse::<Point<f64>>(),
+            Err(ParseError::WrongType { expected: "Point", found: "Pair".to_string() })
+        );
+        assert_eq!("1e12".parse::<Point<f64>>(), Err(ParseError::Unrepresentable("Point")));
+        assert_eq!("e12p + 2e12n".parse::<Line<f64>>(), Err(ParseError::Unrepresentable("Line")));
+        assert_eq!("1x1".parse::<Vector<f64>>(), Err(ParseError::InvalidTerm("1x1".to_string())));
+        assert_eq!("1e1 +".parse::<Vector<f64>>(), Err(ParseError::InvalidTerm("+".to_string())));
+        assert!("".parse::<R410<f64>>().is_err());
+    }
+}
@@ -4,13 +4,17 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
+#![allow(clippy::suspicious_arithmetic_impl)]
 
 use std::fmt::{self, Display, Formatter, LowerExp, UpperExp};
+use std::str::FromStr;
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Not, Shr, Sub};
 
 use num_traits::{One, Zero};
 use simba::simd::SimdRealField as Field;
 
+use crate::ParseError;
+
 /// The general multivector type. Used internally to implement the operations on the various specialized types.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct R410<T> {
@@ -338,35 +342,79 @@ impl<T: Zero + PartialEq> From<T> for R410<T> {
     }
 }
 
-macro_rules! print_fields {
-    ($this:expr, $f:expr, $fmt:literal, ($($field:ident),*)) => {
-        $(
-            if !$this.$field.is_zero() {
-                write!($f, concat!($fmt, stringify!($field)), $this.$field)?;
+/// Writes the nonzero coefficients as a sum of blades, e.g. `1e1 - 0.5e2p`, where the scalar
+/// part has no blade name. `names` holds the blade names in coefficient order and `format`
+/// writes a single coefficient.
+pub(crate) fn write_sum<T: Zero + Copy>(
+    f: &mut impl fmt::Write,
+    v: &R410<T>,
+    names: &[&str; 32],
+    format: impl Fn(T) -> String,
+) -> fmt::Result {
+    let mut first = true;
+    for (i, &c) in v.into_array().iter().enumerate() {
+        if c.is_zero() {
+            continue;
+        }
+        let c = format(c);
+        let (negative, c) = match c.strip_prefix('-') {
+            Some(c) => (true, c),
+            None => (false, c.as_str()),
+        };
+        let sign = match (first, negative) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + ",
+        };
+        first = false;
+        if i == 0 {
+            // A scalar written as `1e1` would read back as a multiple of e1.
+            match c.find('e') {
+                Some(e) if c[e + 1..].starts_with(|d: char| d.is_ascii_digit()) => {
+                    write!(f, "{}{}e+{}", sign, &c[..e], &c[e + 1..])?
+                }
+                _ => write!(f, "{}{}", sign, c)?,
             }
-        )*
-    };
-    ($this:expr, $f:expr, $fmt:literal) => {
-        if !$this.s.is_zero() { write!($f, $fmt, $this.s)?; }
-        print_fields!($this, $f, $fmt, (
-            e1, e2, e3, ep, en, e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn, e123, e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn, e123p, e123n, e12pn, e13pn, e23pn, e123pn
-        ));
+        } else {
+            write!(f, "{}{}{}", sign, c, names[i])?;
+        }
     }
+    if first {
+        write!(f, "{}", format(T::zero()))?;
+    }
+    Ok(())
 }
 
 macro_rules! impl_fmt {
-    ($($trait:ident($fmt:literal)),*) => {$(
-        impl<T: $trait + Zero> $trait for R410<T> {
+    ($($trait:ident($fmt:literal, $precise:literal)),*) => {$(
+        impl<T: $trait + Zero + Copy> $trait for R410<T> {
             #[inline]
             fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-                print_fields!(self, f, $fmt);
-                Ok(())
+                let precision = f.precision();
+                write_sum(f, self, &BLADE_NAMES, |c| match precision {
+                    Some(p) => format!($precise, c, p),
+                    None => format!($fmt, c),
+                })
             }
         }
     )*};
 }
 
-impl_fmt!(Display("{:+}"), LowerExp("{:+e}"), UpperExp("{:+E}"));
+impl_fmt!(
+    Display("{}", "{:.1$}"),
+    LowerExp("{:e}", "{:.1$e}"),
+    UpperExp("{:E}", "{:.1$E}")
+);
+
+impl<T: Field + Copy + FromStr> FromStr for R410<T> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        crate::parse::parse_sum(s)
+    }
+}
 
 /*
 impl<T: Field+Copy+Display> fmt::Display for R410<T> {
@@ -2185,3 +2233,272 @@ impl<T: Field + Copy> R410<T> {
         self / self.norm()
     }
 }
+
+// Blades
+// Table driven access to the basis blades, used for products that are not worth
+// generating out in full.
+
+/// The names of the basis blades in the same order as the fields of `R410`.
+pub(crate) const BLADE_NAMES: [&str; 32] = [
+    "s", "e1", "e2", "e3", "ep", "en", "e12", "e13", "e1p", "e1n", "e23", "e2p", "e2n", "e3p",
+    "e3n", "epn", "e123", "e12p", "e12n", "e13p", "e13n", "e1pn", "e23p", "e23n", "e2pn", "e3pn",
+    "e123p", "e123n", "e12pn", "e13pn", "e23pn", "e123pn",
+];
+
+/// The basis vectors making up each blade as a bitmask of `e1, e2, e3, ep, en`,
+/// in the same order as the fields of `R410`.
+pub(crate) const BLADE_MASKS: [u8; 32] = [
+    0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00011, 0b00101, 0b01001, 0b10001,
+    0b00110, 0b01010, 0b10010, 0b01100, 0b10100, 0b11000, 0b00111, 0b01011, 0b10011, 0b01101,
+    0b10101, 0b11001, 0b01110, 0b10110, 0b11010, 0b11100, 0b01111, 0b10111, 0b11011, 0b11101,
+    0b11110, 0b11111,
+];
+
+/// The bitmask of `en`, the only basis vector that squares to -1.
+const NEGATIVE_MASK: u8 = 0b10000;
+
+/// Looks up the field index of the blade with the given bitmask.
+#[inline]
+pub(crate) const fn blade_index(mask: u8) -> usize {
+    let mut i = 0;
+    while i < 32 {
+        if BLADE_MASKS[i] == mask {
+            return i;
+        }
+        i += 1;
+    }
+    panic!("Invalid blade mask")
+}
+
+/// The grade of the blade at the given field index.
+#[inline]
+pub(crate) const fn blade_grade(index: usize) -> u32 {
+    BLADE_MASKS[index].count_ones()
+}
+
+/// The sign of the geometric product of two basis blades given as bitmasks.
+#[inline]
+pub(crate) const fn blade_product_sign(a: u8, b: u8) -> i8 {
+    // Count the swaps needed to bring the product into canonical order.
+    let mut swaps = 0;
+    let mut a_shifted = a >> 1;
+    while a_shifted != 0 {
+        swaps += (a_shifted & b).count_ones();
+        a_shifted >>= 1;
+    }
+    swaps += (a & b & NEGATIVE_MASK).count_ones();
+    if swaps % 2 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+impl<T: Copy> R410<T> {
+    /// The coefficients in the same order as `BLADE_NAMES`.
+    #[inline]
+    pub(crate) fn into_array(self) -> [T; 32] {
+        [
+            self.s, self.e1, self.e2, self.e3, self.ep, self.en, self.e12, self.e13, self.e1p,
+            self.e1n, self.e23, self.e2p, self.e2n, self.e3p, self.e3n, self.epn, self.e123,
+            self.e12p, self.e12n, self.e13p, self.e13n, self.e1pn, self.e23p, self.e23n,
+            self.e2pn, self.e3pn, self.e123p, self.e123n, self.e12pn, self.e13pn, self.e23pn,
+            self.e123pn,
+        ]
+    }
+
+    /// Builds a multivector from coefficients in the same order as `BLADE_NAMES`.
+    #[inline]
+    pub(crate) fn from_array(a: [T; 32]) -> Self {
+        let [s, e1, e2, e3, ep, en, e12, e13, e1p, e1n, e23, e2p, e2n, e3p, e3n, epn, e123, e12p, e12n, e13p, e13n, e1pn, e23p, e23n, e2pn, e3pn, e123p, e123n, e12pn, e13pn, e23pn, e123pn] =
+            a;
+        Self {
+            s,
+            e1,
+            e2,
+            e3,
+            ep,
+            en,
+            e12,
+            e13,
+            e1p,
+            e1n,
+            e23,
+            e2p,
+            e2n,
+            e3p,
+            e3n,
+            epn,
+            e123,
+            e12p,
+            e12n,
+            e13p,
+            e13n,
+            e1pn,
+            e23p,
+            e23n,
+            e2pn,
+            e3pn,
+            e123p,
+            e123n,
+            e12pn,
+            e13pn,
+            e23pn,
+            e123pn,
+        }
+    }
+}
+
+impl<T: Field + Copy> R410<T> {
+    /// The part of the geometric product between blades of grade `r` and `s` that ends up
+    /// with a grade accepted by `keep(r, s, grade)`.
+    #[inline]
+    fn filtered_product(self, b: Self, keep: impl Fn(u32, u32, u32) -> bool) -> Self {
+        let a = self.into_array();
+        let b = b.into_array();
+        let mut out = [T::zero(); 32];
+        for i in 0..32 {
+            if a[i].is_zero() {
+                continue;
+            }
+            for j in 0..32 {
+                let mask = BLADE_MASKS[i] ^ BLADE_MASKS[j];
+                if !keep(blade_grade(i), blade_grade(j), mask.count_ones()) {
+                    continue;
+                }
+                let k = blade_index(mask);
+                let term = a[i] * b[j];
+                if blade_product_sign(BLADE_MASKS[i], BLADE_MASKS[j]) < 0 {
+                    out[k] -= term;
+                } else {
+                    out[k] += term;
+                }
+            }
+        }
+        Self::from_array(out)
+    }
+
+    /// Extracts the part of the multivector of the given grade.
+    #[inline]
+    pub fn grade(self, grade: u32) -> Self {
+        let mut a = self.into_array();
+        for (i, c) in a.iter_mut().enumerate() {
+            if blade_grade(i) != grade {
+                *c = T::zero();
+            }
+        }
+        Self::from_array(a)
+    }
+
+    /// The left contraction `self ⌋ b`.
+    /// For blades of grade r and s, the grade s - r part of the geometric product, or zero if r > s.
+    #[inline]
+    pub fn left_contraction(self, b: Self) -> Self {
+        self.filtered_product(b, |r, s, g| s >= r && g == s - r)
+    }
+
+    /// The right contraction `self ⌊ b`.
+    /// For blades of grade r and s, the grade r - s part of the geometric product, or zero if s > r.
+    #[inline]
+    pub fn right_contraction(self, b: Self) -> Self {
+        self.filtered_product(b, |r, s, g| r >= s && g == r - s)
+    }
+
+    /// The scalar product `self * b`. The grade 0 part of the geometric product.
+    #[inline]
+    pub fn scalar_product(self, b: Self) -> T {
+        (self * b).s
+    }
+
+    /// The commutator product `(self * b - b * self) / 2`.
+    #[inline]
+    pub fn commutator(self, b: Self) -> Self {
+        (self * b - b * self) * T::from_subset(&0.5)
+    }
+
+    /// The anticommutator product `(self * b + b * self) / 2`.
+    #[inline]
+    pub fn anticommutator(self, b: Self) -> Self {
+        (self * b + b * self) * T::from_subset(&0.5)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample(seed: f64) -> R410<f64> {
+        let mut a = [0.0; 32];
+        for (i, c) in a.iter_mut().enumerate() {
+            *c = ((i as f64 + 1.0) * seed).sin();
+        }
+        R410::from_array(a)
+    }
+
+    #[test]
+    fn test_blade_table() {
+        for (i, &mask) in BLADE_MASKS.iter().enumerate() {
+            assert_eq!(blade_index(mask), i);
+        }
+        let a = sample(0.7);
+        assert_eq!(R410::from_array(a.into_array()), a);
+    }
+
+    #[test]
+    fn test_filtered_product_matches_geometric_product() {
+        let a = sample(0.3);
+        let b = sample(1.1);
+        let table = a.filtered_product(b, |_, _, _| true).into_array();
+        let generated = (a * b).into_array();
+        for i in 0..32 {
+            assert!(
+                (table[i] - generated[i]).abs() < 1e-12,
+                "{}: {} != {}",
+                BLADE_NAMES[i],
+                table[i],
+                generated[i]
+            );
+        }
+    }
+
+    #[test]
+    fn test_contractions() {
+        let e1 = R410::<f64>::e1();
+        let e2 = R410::<f64>::e2();
+        let e12 = R410::<f64>::e12();
+        let en = R410::<f64>::en();
+
+        assert_eq!(e1.left_contraction(e12), e2);
+        assert_eq!(e12.left_contraction(e1), R410::zero());
+        assert_eq!(e12.right_contraction(e2), e1);
+        assert_eq!(e1.right_contraction(e12), R410::zero());
+        assert_eq!(en.left_contraction(en), R410::from(-1.0));
+        assert_eq!(e12.scalar_product(e12), -1.0);
+        assert_eq!(e1.scalar_product(e2), 0.0);
+
+        // Contracting with a scalar scales the other operand.
+        let two = R410::from(2.0);
+        assert_eq!(two.left_contraction(e12), e12 * 2.0);
+        assert_eq!(e12.right_contraction(two), e12 * 2.0);
+    }
+
+    #[test]
+    fn test_commutators() {
+        let e1 = R410::<f64>::e1();
+        let e2 = R410::<f64>::e2();
+        let e12 = R410::<f64>::e12();
+        let e13 = R410::<f64>::e13();
+        let e23 = R410::<f64>::e23();
+
+        assert_eq!(e1.commutator(e2), e12);
+        assert_eq!(e1.anticommutator(e2), R410::zero());
+        assert_eq!(e1.anticommutator(e1), R410::one());
+        assert_eq!(e12.commutator(e23), e13);
+        assert_eq!(e12.commutator(e1), -e2);
+
+        let a = sample(0.3);
+        let b = sample(1.1);
+        let sum = a.commutator(b) + a.anticommutator(b) - a * b;
+        assert!(sum.into_array().iter().all(|c| c.abs() < 1e-12));
+    }
+}
@@ -0,0 +1,710 @@
+//! Generates WGSL and GLSL source for the objects of [`d3`](crate::d3) and a few of the products
+//! between them.
+//!
+//! The products are expanded symbolically from the same blade tables that drive the general
+//! multivector, then projected onto their output type exactly like `Multivec::from_mv`, so the
+//! shaders compute the same values as the Rust implementation. Each struct mirrors the
+//! `#[repr(C)]` layout of its Rust counterpart with `f32` components, so buffers can be filled by
+//! casting `f32` objects. Only Euclidean space is supported, as it's the only one motors act in.
+//!
+//! The generated functions are:
+//!
+//! - `point_join(a: Point, b: Point) -> Pair`, the outer product of two points.
+//! - `motor_point(m: Motor, p: Point) -> Point`, and likewise `motor_sphere` and `motor_dsphere`,
+//!   which apply `m` as in `Transform::transform`.
+//! - `sphere_center(s: Sphere) -> Point` and `dsphere_center(s: DSphere) -> Point`.
+//! - `dsphere_radius(s: DSphere) -> f32`.
+
+use core::mem::{size_of, size_of_val};
+use core::ptr;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::d3::direction::DVector;
+use crate::d3::dual::{DLine, DPlane, DSphere};
+use crate::d3::flat::{FPoint, Line, Plane};
+use crate::d3::free::{Bivector, Trivector, Vector};
+use crate::d3::round::{Circle, Pair, Point, Sphere};
+use crate::d3::transform::{Dilator, Motor, Rotor, Translator};
+use crate::r410::{blade_grade, blade_index, blade_product_sign, BLADE_MASKS};
+use crate::{Euclidean, Multivec, Space, R410};
+
+/// The shading language to generate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Language {
+    Wgsl,
+    Glsl,
+}
+
+/// Generates the struct declarations and functions described in the [module docs](self).
+pub fn generate(language: Language) -> String {
+    let mut out = String::new();
+    write_source(&mut out, language).unwrap();
+    out
+}
+
+/// An object whose `#[repr(C)]` layout is one `f64` per field.
+trait Fields: Multivec<Element = f64> + Copy {
+    const NAME: &'static str;
+    const FIELDS: &'static [&'static str];
+}
+
+macro_rules! impl_fields {
+    ($($t:ident {$($field:ident),*}),*) => {$(
+        impl Fields for $t<f64> {
+            const NAME: &'static str = stringify!($t);
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),*];
+        }
+    )*};
+}
+
+impl_fields!(
+    Point {e1, e2, e3, ep, en},
+    Pair {e12, e13, e23, e1p, e1n, e2p, e2n, e3p, e3n, epn},
+    Circle {e123, e12p, e12n, e13p, e13n, e23p, e23n, e1pn, e2pn, e3pn},
+    Sphere {e123p, e123n, e12pn, e13pn, e23pn},
+    FPoint {e1i, e2i, e3i, epn},
+    Line {e12i, e13i, e23i, e1pn, e2pn, e3pn},
+    Plane {e123i, e12pn, e13pn, e23pn},
+    DLine {e12, e13, e23, e1i, e2i, e3i},
+    DPlane {e1, e2, e3, ei},
+    DSphere {e1, e2, e3, ep, en},
+    DVector {e1i, e2i, e3i},
+    Vector {e1, e2, e3},
+    Bivector {e12, e13, e23},
+    Trivector {e123},
+    Rotor {s, e12, e13, e23},
+    Translator {e1i, e2i, e3i, s},
+    Motor {s, e12, e13, e23, e1i, e2i, e3i, e123i},
+    Dilator {s, epn}
+);
+
+fn from_fields<X: Fields>(values: &[f64]) -> X {
+    assert_eq!(values.len(), X::FIELDS.len());
+    assert_eq!(size_of::<X>(), size_of_val(values));
+    // SAFETY: `X` is `#[repr(C)]` with only `f64` fields and zero-sized markers, and `values`
+    // holds exactly one value per field.
+    unsafe { ptr::read_unaligned(values.as_ptr() as *const X) }
+}
+
+#[cfg(test)]
+fn to_fields<X: Fields>(x: X) -> Vec<f64> {
+    assert_eq!(size_of::<X>(), X::FIELDS.len() * size_of::<f64>());
+    // SAFETY: as in `from_fields`.
+    unsafe { core::slice::from_raw_parts(&x as *const X as *const f64, X::FIELDS.len()) }.to_vec()
+}
+
+/// The multivector an object holds when only the given field is one.
+fn unit<X: Fields>(field: usize) -> R410<f64> {
+    let mut values = vec![0.0; X::FIELDS.len()];
+    values[field] = 1.0;
+    from_fields::<X>(&values).into_mv()
+}
+
+/// A field of one of a function's arguments, as `(argument, field)`.
+type Var = (usize, usize);
+
+/// A polynomial in the fields of the arguments, mapping sorted monomials to their coefficients.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Poly(BTreeMap<Vec<Var>, f64>);
+
+impl Poly {
+    fn add_term(&mut self, monomial: Vec<Var>, coefficient: f64) {
+        let c = self.0.entry(monomial.clone()).or_insert(0.0);
+        *c += coefficient;
+        if *c == 0.0 {
+            self.0.remove(&monomial);
+        }
+    }
+
+    fn add_product(&mut self, a: &Poly, b: &Poly, sign: f64) {
+        for (ma, ca) in &a.0 {
+            for (mb, cb) in &b.0 {
+                let mut monomial = ma.clone();
+                monomial.extend_from_slice(mb);
+                monomial.sort_unstable();
+                self.add_term(monomial, sign * ca * cb);
+            }
+        }
+    }
+
+    fn negate(&mut self) {
+        for c in self.0.values_mut() {
+            *c = -*c;
+        }
+    }
+
+    fn scale(&self, s: f64) -> Poly {
+        Poly(self.0.iter().map(|(m, c)| (m.clone(), c * s)).collect())
+    }
+}
+
+/// A multivector with polynomial coefficients, in the same order as `BLADE_MASKS`.
+#[derive(Clone, Debug, Default)]
+struct Symbolic([Poly; 32]);
+
+impl Symbolic {
+    fn constant(v: R410<f64>) -> Self {
+        let mut out = Self::default();
+        for (p, &c) in out.0.iter_mut().zip(v.into_array().iter()) {
+            p.add_term(Vec::new(), c);
+        }
+        out
+    }
+
+    /// The object passed as the argument `arg`, with each field left as a variable.
+    fn arg<X: Fields>(arg: usize) -> Self {
+        let mut out = Self::default();
+        for field in 0..X::FIELDS.len() {
+            for (p, &c) in out.0.iter_mut().zip(unit::<X>(field).into_array().iter()) {
+                if c != 0.0 {
+                    p.add_term(vec![(arg, field)], c);
+                }
+            }
+        }
+        out
+    }
+
+    /// The part of the geometric product between pairs of blades accepted by `keep`.
+    fn product(&self, rhs: &Self, keep: impl Fn(u8, u8) -> bool) -> Self {
+        let mut out = Self::default();
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in rhs.0.iter().enumerate() {
+                let (ma, mb) = (BLADE_MASKS[i], BLADE_MASKS[j]);
+                if a.0.is_empty() || b.0.is_empty() || !keep(ma, mb) {
+                    continue;
+                }
+                let sign = blade_product_sign(ma, mb) as f64;
+                out.0[blade_index(ma ^ mb)].add_product(a, b, sign);
+            }
+        }
+        out
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.product(rhs, |_, _| true)
+    }
+
+    fn outer(&self, rhs: &Self) -> Self {
+        self.product(rhs, |a, b| a & b == 0)
+    }
+
+    /// Negates the blades of each grade for which `negate` returns true.
+    fn map_grades(&self, negate: impl Fn(u32) -> bool) -> Self {
+        let mut out = self.clone();
+        for (i, p) in out.0.iter_mut().enumerate() {
+            if negate(blade_grade(i)) {
+                p.negate();
+            }
+        }
+        out
+    }
+
+    fn reverse(&self) -> Self {
+        self.map_grades(|g| g * g.saturating_sub(1) / 2 % 2 == 1)
+    }
+
+    fn involute(&self) -> Self {
+        self.map_grades(|g| g % 2 == 1)
+    }
+
+    /// Reads the fields of `Y` out of the multivector, as `Y::from_mv` does.
+    fn project<Y: Fields>(&self) -> Vec<Poly> {
+        (0..Y::FIELDS.len())
+            .map(|field| {
+                // Fields spanning both halves of a blade containing infinity average the two,
+                // just like `Euclidean::join`.
+                let u = unit::<Y>(field).into_array();
+                let halves: Vec<usize> = (0..32).filter(|&i| u[i] != 0.0).collect();
+                let mut sum = Poly::default();
+                for &i in &halves {
+                    for (m, c) in &self.0[i].0 {
+                        sum.add_term(m.clone(), c / u[i]);
+                    }
+                }
+                sum.scale(1.0 / halves.len() as f64)
+            })
+            .collect()
+    }
+}
+
+struct Param {
+    name: &'static str,
+    ty: &'static str,
+    fields: &'static [&'static str],
+}
+
+fn param<X: Fields>(name: &'static str) -> Param {
+    Param {
+        name,
+        ty: X::NAME,
+        fields: X::FIELDS,
+    }
+}
+
+enum Body {
+    /// Constructs an object of the named type from its fields.
+    Object(&'static str, Vec<Poly>),
+    /// Constructs an object from its fields, each divided by the given weight.
+    Weighted(&'static str, Vec<Poly>, Poly),
+    /// Returns the square root of a scalar.
+    Sqrt(Poly),
+}
+
+struct Function {
+    name: &'static str,
+    params: Vec<Param>,
+    body: Body,
+}
+
+fn functions() -> Vec<Function> {
+    let ni = Symbolic::constant(Euclidean::infinity());
+
+    let join = {
+        let a = Symbolic::arg::<Point<f64>>(0);
+        let b = Symbolic::arg::<Point<f64>>(1);
+        Body::Object(Pair::<f64>::NAME, a.outer(&b).project::<Pair<f64>>())
+    };
+
+    fn sandwich<X: Fields>() -> Body {
+        let m = Symbolic::arg::<Motor<f64>>(0);
+        let x = Symbolic::arg::<X>(1);
+        Body::Object(X::NAME, m.involute().mul(&x).mul(&m.reverse()).project::<X>())
+    }
+
+    let sphere_center = {
+        let s = Symbolic::arg::<Sphere<f64>>(0);
+        let p = s.mul(&ni).mul(&s);
+        let w = p.mul(&ni).0[0].scale(-1.0);
+        Body::Weighted(Point::<f64>::NAME, p.project::<Point<f64>>(), w)
+    };
+
+    let dsphere_center = {
+        let s = Symbolic::arg::<DSphere<f64>>(0);
+        let p = s.mul(&ni).mul(&s);
+        let w = p.mul(&ni).0[0].scale(-1.0);
+        Body::Weighted(Point::<f64>::NAME, p.project::<Point<f64>>(), w)
+    };
+
+    let dsphere_radius = {
+        let s = Symbolic::arg::<DSphere<f64>>(0);
+        Body::Sqrt(s.mul(&s).0[0].clone())
+    };
+
+    vec![
+        Function {
+            name: "point_join",
+            params: vec![param::<Point<f64>>("a"), param::<Point<f64>>("b")],
+            body: join,
+        },
+        Function {
+            name: "motor_point",
+            params: vec![param::<Motor<f64>>("m"), param::<Point<f64>>("p")],
+            body: sandwich::<Point<f64>>(),
+        },
+        Function {
+            name: "motor_sphere",
+            params: vec![param::<Motor<f64>>("m"), param::<Sphere<f64>>("s")],
+            body: sandwich::<Sphere<f64>>(),
+        },
+        Function {
+            name: "motor_dsphere",
+            params: vec![param::<Motor<f64>>("m"), param::<DSphere<f64>>("s")],
+            body: sandwich::<DSphere<f64>>(),
+        },
+        Function {
+            name: "sphere_center",
+            params: vec![param::<Sphere<f64>>("s")],
+            body: sphere_center,
+        },
+        Function {
+            name: "dsphere_center",
+            params: vec![param::<DSphere<f64>>("s")],
+            body: dsphere_center,
+        },
+        Function {
+            name: "dsphere_radius",
+            params: vec![param::<DSphere<f64>>("s")],
+            body: dsphere_radius,
+        },
+    ]
+}
+
+fn write_struct<X: Fields>(out: &mut String, language: Language) -> fmt::Result {
+    writeln!(out, "struct {} {{", X::NAME)?;
+    for field in X::FIELDS {
+        match language {
+            Language::Wgsl => writeln!(out, "    {}: f32,", field)?,
+            Language::Glsl => writeln!(out, "    float {};", field)?,
+        }
+    }
+    match language {
+        Language::Wgsl => writeln!(out, "}}"),
+        Language::Glsl => writeln!(out, "}};"),
+    }
+}
+
+fn write_poly(out: &mut String, poly: &Poly, params: &[Param]) -> fmt::Result {
+    if poly.0.is_empty() {
+        return write!(out, "0.0");
+    }
+    for (i, (monomial, &c)) in poly.0.iter().enumerate() {
+        match (i, c < 0.0) {
+            (0, true) => write!(out, "-")?,
+            (0, false) => {}
+            (_, true) => write!(out, " - ")?,
+            (_, false) => write!(out, " + ")?,
+        }
+        if monomial.is_empty() {
+            write!(out, "{:?}", c.abs())?;
+            continue;
+        }
+        if c.abs() != 1.0 {
+            write!(out, "{:?} * ", c.abs())?;
+        }
+        for (j, &(arg, field)) in monomial.iter().enumerate() {
+            if j > 0 {
+                write!(out, " * ")?;
+            }
+            let p = &params[arg];
+            write!(out, "{}.{}", p.name, p.fields[field])?;
+        }
+    }
+    Ok(())
+}
+
+fn write_function(out: &mut String, f: &Function, language: Language) -> fmt::Result {
+    let returns = match (&f.body, language) {
+        (Body::Object(ty, _), _) | (Body::Weighted(ty, _, _), _) => ty,
+        (Body::Sqrt(_), Language::Wgsl) => "f32",
+        (Body::Sqrt(_), Language::Glsl) => "float",
+    };
+    match language {
+        Language::Wgsl => {
+            write!(out, "fn {}(", f.name)?;
+            for (i, p) in f.params.iter().enumerate() {
+                let sep = if i > 0 { ", " } else { "" };
+                write!(out, "{}{}: {}", sep, p.name, p.ty)?;
+            }
+            writeln!(out, ") -> {} {{", returns)?;
+        }
+        Language::Glsl => {
+            write!(out, "{} {}(", returns, f.name)?;
+            for (i, p) in f.params.iter().enumerate() {
+                let sep = if i > 0 { ", " } else { "" };
+                write!(out, "{}{} {}", sep, p.ty, p.name)?;
+            }
+            writeln!(out, ") {{")?;
+        }
+    }
+
+    let (ty, fields, weighted) = match &f.body {
+        Body::Sqrt(poly) => {
+            write!(out, "    return sqrt(")?;
+            write_poly(out, poly, &f.params)?;
+            writeln!(out, ");")?;
+            return writeln!(out, "}}");
+        }
+        Body::Object(ty, fields) => (ty, fields, false),
+        Body::Weighted(ty, fields, weight) => {
+            match language {
+                Language::Wgsl => write!(out, "    let w = ")?,
+                Language::Glsl => write!(out, "    float w = ")?,
+            }
+            write_poly(out, weight, &f.params)?;
+            writeln!(out, ";")?;
+            (ty, fields, true)
+        }
+    };
+    writeln!(out, "    return {}(", ty)?;
+    for (i, poly) in fields.iter().enumerate() {
+        write!(out, "        ")?;
+        if weighted {
+            write!(out, "(")?;
+        }
+        write_poly(out, poly, &f.params)?;
+        if weighted {
+            write!(out, ") / w")?;
+        }
+        writeln!(out, "{}", if i + 1 < fields.len() { "," } else { "" })?;
+    }
+    writeln!(out, "    );")?;
+    writeln!(out, "}}")
+}
+
+fn write_source(out: &mut String, language: Language) -> fmt::Result {
+    writeln!(out, "// Generated by cga. Objects are in Euclidean space.")?;
+    macro_rules! structs {
+        ($($t:ident),*) => {$(
+            writeln!(out)?;
+            write_struct::<$t<f64>>(out, language)?;
+        )*};
+    }
+    structs!(
+        Point, Pair, Circle, Sphere, FPoint, Line, Plane, DLine, DPlane, DSphere, DVector,
+        Vector, Bivector, Trivector, Rotor, Translator, Motor, Dilator
+    );
+    for f in functions() {
+        writeln!(out)?;
+        write_function(out, &f, language)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::d3::Transform;
+    use crate::Outer;
+
+    /// A tiny interpreter for the subset of WGSL and GLSL that `generate` emits.
+    struct Interpreter<'a> {
+        tokens: Vec<&'a str>,
+        pos: usize,
+        env: HashMap<String, f64>,
+    }
+
+    enum Value {
+        Scalar(f64),
+        Object(String, Vec<f64>),
+    }
+
+    fn tokenize(src: &str) -> Vec<&str> {
+        let mut tokens = Vec::new();
+        let mut rest = src.trim_start();
+        while !rest.is_empty() {
+            let c = rest.chars().next().unwrap();
+            let len = if c.is_alphanumeric() || c == '_' || c == '.' {
+                rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                    .unwrap_or(rest.len())
+            } else if rest.starts_with("->") {
+                2
+            } else {
+                1
+            };
+            tokens.push(&rest[..len]);
+            rest = rest[len..].trim_start();
+        }
+        tokens
+    }
+
+    impl<'a> Interpreter<'a> {
+        fn next(&mut self) -> &'a str {
+            self.pos += 1;
+            self.tokens[self.pos - 1]
+        }
+
+        fn expect(&mut self, token: &str) {
+            assert_eq!(self.next(), token);
+        }
+
+        fn peek(&self) -> &'a str {
+            self.tokens[self.pos]
+        }
+
+        fn expr(&mut self) -> f64 {
+            let mut value = self.term();
+            loop {
+                match self.peek() {
+                    "+" => {
+                        self.next();
+                        value += self.term();
+                    }
+                    "-" => {
+                        self.next();
+                        value -= self.term();
+                    }
+                    _ => return value,
+                }
+            }
+        }
+
+        fn term(&mut self) -> f64 {
+            let mut value = self.factor();
+            loop {
+                match self.peek() {
+                    "*" => {
+                        self.next();
+                        value *= self.factor();
+                    }
+                    "/" => {
+                        self.next();
+                        value /= self.factor();
+                    }
+                    _ => return value,
+                }
+            }
+        }
+
+        fn factor(&mut self) -> f64 {
+            match self.next() {
+                "-" => -self.factor(),
+                "(" => {
+                    let value = self.expr();
+                    self.expect(")");
+                    value
+                }
+                t if t.starts_with(|c: char| c.is_ascii_digit()) => t.parse().unwrap(),
+                t => self.env[t],
+            }
+        }
+
+        /// Runs the function `name` from `src` on arguments given by type name and fields.
+        fn run(src: &'a str, name: &str, args: &[(&str, Vec<f64>)]) -> Value {
+            let tokens = tokenize(src);
+            let start = tokens.iter().position(|&t| t == name).unwrap();
+            let mut interp = Interpreter {
+                tokens,
+                pos: start + 1,
+                env: HashMap::new(),
+            };
+            interp.expect("(");
+            for (ty, values) in args {
+                // Parameters are `name: Type` in WGSL and `Type name` in GLSL.
+                let (a, b) = (interp.next(), interp.next());
+                let (param, declared) = if b == ":" { (a, interp.next()) } else { (b, a) };
+                assert_eq!(declared, *ty);
+                let fields = fields_of(ty);
+                for (field, value) in fields.iter().zip(values) {
+                    interp.env.insert(format!("{}.{}", param, field), *value);
+                }
+                if interp.peek() == "," {
+                    interp.next();
+                }
+            }
+            interp.expect(")");
+            while interp.next() != "{" {}
+            loop {
+                match interp.next() {
+                    "let" | "float" => {
+                        let var = interp.next();
+                        interp.expect("=");
+                        let value = interp.expr();
+                        interp.expect(";");
+                        interp.env.insert(var.to_string(), value);
+                    }
+                    "return" => {
+                        let ty = interp.next();
+                        interp.expect("(");
+                        let mut values = vec![interp.expr()];
+                        while interp.next() == "," {
+                            values.push(interp.expr());
+                        }
+                        return match ty {
+                            "sqrt" => Value::Scalar(values[0].sqrt()),
+                            _ => Value::Object(ty.to_string(), values),
+                        };
+                    }
+                    t => panic!("unexpected token {}", t),
+                }
+            }
+        }
+    }
+
+    fn fields_of(ty: &str) -> &'static [&'static str] {
+        match ty {
+            "Point" => Point::<f64>::FIELDS,
+            "Sphere" => Sphere::<f64>::FIELDS,
+            "DSphere" => DSphere::<f64>::FIELDS,
+            "Motor" => Motor::<f64>::FIELDS,
+            _ => panic!("unexpected type {}", ty),
+        }
+    }
+
+    fn arg<X: Fields>(x: X) -> (&'static str, Vec<f64>) {
+        (X::NAME, to_fields(x))
+    }
+
+    fn assert_object<X: Fields>(value: Value, expected: X) {
+        match value {
+            Value::Object(ty, values) => {
+                assert_eq!(ty, X::NAME);
+                for (a, e) in values.iter().zip(to_fields(expected)) {
+                    assert_relative_eq!(*a, e, epsilon = 1e-9, max_relative = 1e-9);
+                }
+            }
+            Value::Scalar(_) => panic!("expected a {}", X::NAME),
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        // Every field must hold the blade it is named after, or both halves for `i` fields.
+        fn check<X: Fields>() {
+            for (field, name) in X::FIELDS.iter().enumerate() {
+                let mv = unit::<X>(field).into_array();
+                let blades: Vec<_> = (0..32)
+                    .filter(|&i| mv[i] != 0.0)
+                    .map(|i| crate::r410::BLADE_NAMES[i])
+                    .collect();
+                match name.strip_suffix('i') {
+                    Some(base) => assert_eq!(blades, [format!("{}p", base), format!("{}n", base)]),
+                    None => assert_eq!(blades, [*name]),
+                }
+            }
+        }
+        check::<Point<f64>>();
+        check::<Pair<f64>>();
+        check::<Circle<f64>>();
+        check::<Sphere<f64>>();
+        check::<FPoint<f64>>();
+        check::<Line<f64>>();
+        check::<Plane<f64>>();
+        check::<DLine<f64>>();
+        check::<DPlane<f64>>();
+        check::<DSphere<f64>>();
+        check::<DVector<f64>>();
+        check::<Vector<f64>>();
+        check::<Bivector<f64>>();
+        check::<Trivector<f64>>();
+        check::<Rotor<f64>>();
+        check::<Translator<f64>>();
+        check::<Motor<f64>>();
+        check::<Dilator<f64>>();
+    }
+
+    #[test]
+    fn test_golden() {
+        let wgsl = generate(Language::Wgsl);
+        assert!(wgsl.contains("struct Point {\n    e1: f32,\n    e2: f32,\n    e3: f32,\n"));
+        assert!(wgsl.contains("fn point_join(a: Point, b: Point) -> Pair {\n    return Pair(\n        a.e1 * b.e2 - a.e2 * b.e1,\n"));
+        assert!(wgsl.contains("fn dsphere_radius(s: DSphere) -> f32 {\n    return sqrt(s.e1 * s.e1 + "));
+
+        let glsl = generate(Language::Glsl);
+        assert!(glsl.contains("struct Motor {\n    float s;\n    float e12;\n"));
+        assert!(glsl.contains("Pair point_join(Point a, Point b) {\n"));
+        assert!(glsl.contains("    float w = "));
+    }
+
+    #[test]
+    fn test_semantics() {
+        let p1 = Point::<f64>::new([1.0, 0.0, 0.0]);
+        let p2 = Point::new([3.0, 4.0, 5.0]);
+        let p3 = Point::new([-2.0, 1.0, 0.5]);
+        let p4 = Point::new([0.0, -1.0, 2.0]);
+        let sphere = p1.outer(p2).outer(p3).outer(p4);
+        let dsphere = p3.into_sphere(2.0);
+        let r = Rotor::from_axis_angle(Vector::new(1.0, 2.0, -1.0), 0.8);
+        let m = Motor::new(r, Vector::new(3.0, -1.0, 0.25));
+
+        for &language in &[Language::Wgsl, Language::Glsl] {
+            let src = generate(language);
+            let run = |name, args: &[_]| Interpreter::run(&src, name, args);
+
+            assert_object(run("point_join", &[arg(p1), arg(p2)]), p1.outer(p2));
+            assert_object(run("motor_point", &[arg(m), arg(p2)]), m.transform(p2));
+            assert_object(run("motor_sphere", &[arg(m), arg(sphere)]), m.transform(sphere));
+            assert_object(run("motor_dsphere", &[arg(m), arg(dsphere)]), m.transform(dsphere));
+            assert_object(run("sphere_center", &[arg(sphere)]), sphere.center());
+            assert_object(run("dsphere_center", &[arg(dsphere)]), dsphere.center());
+            match run("dsphere_radius", &[arg(dsphere)]) {
+                Value::Scalar(radius) => assert_relative_eq!(radius, dsphere.radius()),
+                Value::Object(..) => panic!("expected a scalar"),
+            }
+        }
+    }
+}
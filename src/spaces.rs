@@ -6,14 +6,37 @@ use core::cmp::PartialEq;
 
 use num_traits::{one, zero};
 
-use crate::{Field, R410};
+use crate::{Error, Field, R410};
 
 pub trait Space: PartialEq + Copy {
     /// Hyperbolic and Spherical geometry appear to swap when taking the dual.
     type Dual: Space;
+    /// Identifies the space when objects are serialized.
+    const NAME: &'static str;
     fn split<T: Field + Copy>(e: T) -> IBasis<T>;
+    /// Combines the `ep` and `en` halves of a multiple of infinity. Whatever doesn't lie along
+    /// infinity is dropped, so slightly inconsistent halves from round-off are tolerated.
     fn join<T: Field + Copy>(ep: T, en: T) -> T;
 
+    /// Whether `ep` and `en` are the halves of a single multiple of infinity, so that `join`
+    /// combines them without losing anything.
+    #[inline]
+    fn joins<T: Field + Copy + PartialEq>(ep: T, en: T) -> bool {
+        let IBasis { ep: p, en: n } = Self::split(one());
+        ep * n == en * p
+    }
+
+    /// Combines `ep` and `en` like `join`, but fails instead of dropping part of them when they
+    /// aren't a multiple of infinity.
+    #[inline]
+    fn try_join<T: Field + Copy + PartialEq>(ep: T, en: T) -> Result<T, Error> {
+        if Self::joins(ep, en) {
+            Ok(Self::join(ep, en))
+        } else {
+            Err(Error::InconsistentNull)
+        }
+    }
+
     #[inline]
     fn infinity<T: Field + Copy>() -> R410<T> {
         let IBasis { ep, en } = Self::split(one());
@@ -38,6 +61,7 @@ pub struct Spherical;
 
 impl Space for Euclidean {
     type Dual = Euclidean;
+    const NAME: &'static str = "Euclidean";
 
     #[inline]
     fn split<T: Field + Copy>(e: T) -> IBasis<T> {
@@ -45,13 +69,13 @@ impl Space for Euclidean {
     }
     #[inline]
     fn join<T: Field + Copy>(ep: T, en: T) -> T {
-        debug_assert_eq!(ep, en);
-        ep
+        (ep + en) / (one::<T>() + one())
     }
 }
 
 impl Space for Hyperbolic {
     type Dual = Spherical;
+    const NAME: &'static str = "Hyperbolic";
 
     #[inline]
     fn split<T: Field + Copy>(ep: T) -> IBasis<T> {
@@ -65,6 +89,7 @@ impl Space for Hyperbolic {
 
 impl Space for Spherical {
     type Dual = Hyperbolic;
+    const NAME: &'static str = "Spherical";
 
     #[inline]
     fn split<T: Field + Copy>(en: T) -> IBasis<T> {
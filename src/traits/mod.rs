@@ -7,13 +7,13 @@
 
 
 macro_rules! impl_simd_value {
-    ($t:ident{$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
-        impl<T> simba::simd::SimdValue for $t<T>
+    ($t:ident $(<$s:ident>)? {$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
+        impl<T $(, $s: crate::Space)?> simba::simd::SimdValue for $t<T $(, $s)?>
         where
             T: simba::simd::SimdValue,
             <T as simba::simd::SimdValue>::Element: simba::scalar::RealField,
         {
-            type Element = $t<T::Element>;
+            type Element = $t<T::Element $(, $s)?>;
             type SimdBool = T::SimdBool;
             fn lanes() -> usize {
                 T::lanes()
@@ -41,27 +41,145 @@ macro_rules! impl_simd_value {
 }
 
 macro_rules! impl_subset_of {
-    ($t:ident{$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
-        impl<T, U> simba::scalar::SubsetOf<$t<U>> for $t<T>
+    ($t:ident $(<$s:ident>)? {$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
+        impl<T, U $(, $s: crate::Space)?> simba::scalar::SubsetOf<$t<U $(, $s)?>> for $t<T $(, $s)?>
         where
             U: simba::scalar::SupersetOf<T>,
         {
-            fn to_superset(&self) -> $t<U> {
+            fn to_superset(&self) -> $t<U $(, $s)?> {
                 $t { $($field: U::from_subset(&self.$field),)* $($extra: $e,)* }
             }
-            fn from_superset_unchecked(element: &$t<U>) -> Self {
+            fn from_superset_unchecked(element: &$t<U $(, $s)?>) -> Self {
                 $t { $($field: element.$field.to_subset_unchecked(),)* $($extra: $e,)* }
             }
-            fn is_in_subset(element: &$t<U>) -> bool {
+            fn is_in_subset(element: &$t<U $(, $s)?>) -> bool {
                 $(element.$field.is_in_subset())&&*
             }
         }
     }
 }
 
+macro_rules! impl_lanes {
+    ($t:ident $(<$s:ident>)?) => {
+        impl<T $(, $s: crate::Space)?> $t<T $(, $s)?>
+        where
+            T: simba::simd::SimdValue,
+            <T as simba::simd::SimdValue>::Element: simba::scalar::RealField,
+        {
+            /// Packs one object per SIMD lane into a single object.
+            /// Panics if `N` differs from the number of lanes.
+            #[inline]
+            pub fn from_lanes<const N: usize>(lanes: [$t<T::Element $(, $s)?>; N]) -> Self {
+                use simba::simd::SimdValue;
+                assert_eq!(N, T::lanes(), "Wrong number of lanes");
+                let mut lanes = IntoIterator::into_iter(lanes);
+                let mut packed = Self::splat(lanes.next().expect("Wrong number of lanes"));
+                for (i, lane) in lanes.enumerate() {
+                    packed.replace(i + 1, lane);
+                }
+                packed
+            }
+
+            /// Unpacks the object in each SIMD lane.
+            /// Panics if `N` differs from the number of lanes.
+            #[inline]
+            pub fn to_lanes<const N: usize>(&self) -> [$t<T::Element $(, $s)?>; N] {
+                use simba::simd::SimdValue;
+                assert_eq!(N, T::lanes(), "Wrong number of lanes");
+                core::array::from_fn(|i| self.extract(i))
+            }
+        }
+    };
+}
+
+macro_rules! impl_format {
+    ($t:ident $(<$s:ident>)?) => {
+        impl_format! { $t $(<$s>)? fmt Display }
+        impl_format! { $t $(<$s>)? fmt LowerExp }
+        impl_format! { $t $(<$s>)? fmt UpperExp }
+
+        /// Parses the object from a sum of blades, optionally wrapped in the type name as written
+        /// by `Display`. Fails if the sum has components the object can't hold.
+        impl<T $(, $s: crate::Space)?> core::str::FromStr for $t<T $(, $s)?>
+        where
+            T: crate::Field + Copy + PartialEq + core::str::FromStr,
+        {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, crate::ParseError> {
+                crate::parse::parse_object(stringify!($t), s)
+            }
+        }
+    };
+    ($t:ident $(<$s:ident>)? fmt $trait:ident) => {
+        /// Writes the object as its type name around a sum of blades, e.g. `Point(1e1 + 0.5en)`.
+        impl<T $(, $s: crate::Space)?> core::fmt::$trait for $t<T $(, $s)?>
+        where
+            T: crate::Field + Copy + core::fmt::$trait,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, concat!(stringify!($t), "("))?;
+                core::fmt::$trait::fmt(&crate::Multivec::into_mv(*self), f)?;
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx {
+    ($t:ident $(<$s:ident>)? {$($field:ident,)*}) => {
+        impl<T $(, $s: crate::Space)?> approx::AbsDiffEq for $t<T $(, $s)?>
+        where
+            T: approx::AbsDiffEq,
+            T::Epsilon: Copy,
+        {
+            type Epsilon = T::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(T::abs_diff_eq(&self.$field, &other.$field, epsilon))&&*
+            }
+        }
+
+        impl<T $(, $s: crate::Space)?> approx::RelativeEq for $t<T $(, $s)?>
+        where
+            T: approx::RelativeEq,
+            T::Epsilon: Copy,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $(T::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&*
+            }
+        }
+
+        impl<T $(, $s: crate::Space)?> approx::UlpsEq for $t<T $(, $s)?>
+        where
+            T: approx::UlpsEq,
+            T::Epsilon: Copy,
+        {
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $(T::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&*
+            }
+        }
+    };
+}
+
 macro_rules! impl_traits {
-    ($t:ident{$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
-        impl_simd_value! { $t { $($field,)* [$($extra: $e),*] } }
-        impl_subset_of! { $t { $($field,)* [$($extra: $e),*] } }
+    ($t:ident $(<$s:ident>)? {$($field:ident,)* [$($extra:ident: $e:expr),*]}) => {
+        impl_simd_value! { $t $(<$s>)? { $($field,)* [$($extra: $e),*] } }
+        impl_subset_of! { $t $(<$s>)? { $($field,)* [$($extra: $e),*] } }
+        impl_lanes! { $t $(<$s>)? }
+        impl_format! { $t $(<$s>)? }
+        impl_approx! { $t $(<$s>)? { $($field,)* } }
     }
 }